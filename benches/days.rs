@@ -0,0 +1,96 @@
+use std::hint::black_box;
+
+use aoc::input::InputSource;
+use aoc::solution::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc::y2023::day1::{self, Day1, Lexicon, Matcher};
+use aoc::y2023::day10::Day10;
+use aoc::y2023::day2::Day2;
+use aoc::y2023::day3::Day3;
+use aoc::y2023::day4::Day4;
+use aoc::y2023::day5::Day5;
+use aoc::y2023::day6::Day6;
+use aoc::y2023::day7::Day7;
+use aoc::y2023::day8::Day8;
+use aoc::y2023::day9::Day9;
+
+/// Benchmarks parsing and each of the given parts separately, as `YYYY/dayN/parse`,
+/// `YYYY/dayN/part_1` and `YYYY/dayN/part_2`, so that e.g. `cargo bench -- 2023/day5/` only runs
+/// day 5 of 2023.
+fn bench_day<S: Solution>(c: &mut Criterion, parts: &[u8]) {
+    let input = match InputSource::default().read(S::YEAR, S::DAY) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("skipping {} day {}: {e}", S::YEAR, S::DAY);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("{}/day{}", S::YEAR, S::DAY));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input)).unwrap()));
+
+    let parsed = S::parse(&input).unwrap();
+
+    if parts.contains(&1) {
+        group.bench_function("part_1", |b| b.iter(|| S::part_1(black_box(&parsed))));
+    }
+
+    if parts.contains(&2) {
+        group.bench_function("part_2", |b| b.iter(|| S::part_2(black_box(&parsed))));
+    }
+
+    group.finish();
+}
+
+/// Compares the two ways day 1 can find the first and last digits of every line: the two-pointer
+/// scan that tries every word at every position, and the automata that `Day1` uses.
+fn day1_matchers(c: &mut Criterion) {
+    let input = match InputSource::default().read(2023, 1) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("skipping 2023 day 1 matchers: {e}");
+            return;
+        }
+    };
+
+    let lexicon = Lexicon::english();
+    let matcher = Matcher::new(&lexicon);
+    let mut group = c.benchmark_group("2023/day1/matchers");
+
+    group.bench_function("two_pointer", |b| {
+        b.iter(|| {
+            for line in black_box(&input).lines() {
+                black_box(day1::find_first_last(line, &lexicon));
+            }
+        })
+    });
+
+    group.bench_function("automaton", |b| {
+        b.iter(|| {
+            for line in black_box(&input).lines() {
+                black_box(matcher.find_first_last(line));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<Day1>(c, &[1, 2]);
+    bench_day::<Day2>(c, &[1, 2]);
+    bench_day::<Day3>(c, &[1, 2]);
+    bench_day::<Day4>(c, &[1, 2]);
+    bench_day::<Day5>(c, &[1, 2]);
+    bench_day::<Day6>(c, &[1, 2]);
+    bench_day::<Day7>(c, &[1, 2]);
+    bench_day::<Day8>(c, &[1, 2]);
+    bench_day::<Day9>(c, &[1, 2]);
+    // part 2 hasn't been solved yet
+    bench_day::<Day10>(c, &[1]);
+}
+
+criterion_group!(benches, days, day1_matchers);
+criterion_main!(benches);
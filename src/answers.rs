@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use color_eyre::Result;
+
+use crate::records::{self, Records};
+
+/// Where recorded answers are kept when no other file is configured.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.txt";
+
+/// Identifies one part of one day of one year, solved against one particular input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AnswerKey {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    /// See [`input_hash`].
+    pub input: u64,
+}
+
+/// Hashes a puzzle input so answers can be tied to the input they were computed from. This is
+/// FNV-1a rather than std's `DefaultHasher`, since the hashes are written to disk and have to stay
+/// the same across Rust versions. Leading and trailing whitespace is ignored.
+pub fn input_hash(input: &str) -> u64 {
+    input.trim().bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The answers we know to be right, stored as lines of `year day part input-hash answer`.
+#[derive(Debug, Default, PartialEq)]
+pub struct AnswerStore {
+    answers: BTreeMap<AnswerKey, String>,
+}
+
+/// The result of checking a computed answer against the store.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: String,
+    },
+    /// Nothing has been recorded for this day, part and input yet.
+    Missing,
+}
+
+impl AnswerStore {
+    /// Loads the store from a file. A file that doesn't exist yet is just an empty store.
+    pub fn load(path: &Path) -> Result<Self> {
+        records::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        records::save(self, path)
+    }
+
+    pub fn get(&self, key: &AnswerKey) -> Option<&str> {
+        self.answers.get(key).map(String::as_str)
+    }
+
+    /// Records an answer, replacing anything previously recorded for the same key.
+    pub fn record(&mut self, key: AnswerKey, answer: impl Into<String>) {
+        self.answers.insert(key, answer.into());
+    }
+
+    pub fn check(&self, key: &AnswerKey, answer: &str) -> Verdict {
+        match self.get(key) {
+            Some(expected) if expected == answer => Verdict::Match,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl Records for AnswerStore {
+    const NAME: &'static str = "answers";
+    const FIELDS: &'static [&'static str] = &["answer"];
+
+    fn insert(&mut self, key: AnswerKey, fields: &[&str]) -> Option<()> {
+        self.record(key, fields[0]);
+        Some(())
+    }
+
+    fn records(&self) -> Vec<(AnswerKey, Vec<String>)> {
+        self.answers
+            .iter()
+            .map(|(key, answer)| (*key, vec![answer.clone()]))
+            .collect()
+    }
+}
+
+impl std::str::FromStr for AnswerStore {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        records::parse(s)
+    }
+}
+
+impl std::fmt::Display for AnswerStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&records::format(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(day: u32, part: u8) -> AnswerKey {
+        AnswerKey {
+            year: 2023,
+            day,
+            part,
+            input: input_hash("some input"),
+        }
+    }
+
+    #[test]
+    fn test_input_hash_ignores_surrounding_whitespace() {
+        assert_eq!(input_hash("1 2 3\n"), input_hash("1 2 3"));
+        assert_ne!(input_hash("1 2 3"), input_hash("1 2 4"));
+        // the FNV-1a offset basis
+        assert_eq!(input_hash(""), 0xcbf29ce484222325);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut store = AnswerStore::default();
+        store.record(key(5, 1), "35");
+        store.record(key(5, 2), "46");
+        store.record(key(10, 1), "some text answer");
+
+        let parsed: AnswerStore = store.to_string().parse().unwrap();
+        assert_eq!(parsed, store);
+        assert_eq!(parsed.get(&key(10, 1)), Some("some text answer"));
+    }
+
+    #[test]
+    fn test_check() {
+        let mut store = AnswerStore::default();
+        store.record(key(3, 1), "4361");
+
+        assert_eq!(store.check(&key(3, 1), "4361"), Verdict::Match);
+        assert_eq!(
+            store.check(&key(3, 1), "4360"),
+            Verdict::Mismatch {
+                expected: "4361".to_string()
+            }
+        );
+        assert_eq!(store.check(&key(3, 2), "467835"), Verdict::Missing);
+
+        let other_input = AnswerKey {
+            input: input_hash("another input"),
+            ..key(3, 1)
+        };
+        assert_eq!(store.check(&other_input, "4361"), Verdict::Missing);
+    }
+
+    #[test]
+    fn test_bad_line() {
+        let err = "# comment\n2023 1 1 not-hex 55386".parse::<AnswerStore>();
+        assert!(err.unwrap_err().to_string().contains("line 2"));
+    }
+}
@@ -0,0 +1,529 @@
+use std::path::{Path, PathBuf};
+
+use aoc::answers::{input_hash, AnswerKey, AnswerStore, Verdict, DEFAULT_ANSWERS_FILE};
+use aoc::client::{self, Client, Fetched, DEFAULT_BASE_URL};
+use aoc::guesses::{GuessLog, Outcome, DEFAULT_GUESSES_FILE};
+use aoc::input::{InputSource, DEFAULT_INPUT_DIR};
+use aoc::report;
+use aoc::runner::{self, Day, DEFAULT_YEAR};
+use aoc::scaffold;
+use aoc::solution::Answer;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use color_eyre::eyre::{eyre, Report};
+use color_eyre::{Result, Section, SectionExt};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solution for a single day, or for every day
+    Run(RunArgs),
+    /// Checks every day's answers against the answers recorded for its input
+    Verify(VerifyArgs),
+    /// Creates and registers the module, input and example fixture for a new day
+    New(NewArgs),
+    /// Downloads puzzle inputs that haven't been downloaded yet
+    Fetch(FetchArgs),
+    /// Submits the computed answer for one part of a day, unless it's already known to be wrong
+    Submit(SubmitArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The year of the event
+    #[arg(short, long, env = "AOC_YEAR", default_value_t = DEFAULT_YEAR)]
+    year: u32,
+
+    /// The day to run
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u32>,
+
+    /// Only run this part (both parts are run by default)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run every registered day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Read the puzzle input from this file instead of the input directory ("-" reads stdin)
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// The directory containing each year's `YYYY/day_N.txt` inputs
+    #[arg(long, env = "AOC_INPUT_DIR", default_value = DEFAULT_INPUT_DIR)]
+    input_dir: PathBuf,
+
+    /// How to print the answers
+    #[arg(short, long, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+
+    /// Run the days in parallel (the times in the table then include the contention)
+    #[arg(long, requires = "all")]
+    parallel: bool,
+
+    /// Also draw the day's input and what the solution made of it to this .svg or .ppm file, for
+    /// the days that support it
+    #[arg(long, value_name = "FILE", requires = "day", conflicts_with = "format")]
+    render: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    /// A `day N part P: answer` line per part
+    Plain,
+    /// A table of answers and how long parsing and each part took
+    Table,
+    /// The same table, in Markdown
+    Markdown,
+    /// A JSON array with the answer, duration and any error for each part of each day
+    Json,
+}
+
+impl RunArgs {
+    fn input_source(&self) -> InputSource {
+        match &self.input {
+            Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+            Some(path) => InputSource::File(path.clone()),
+            None => InputSource::Dir(self.input_dir.clone()),
+        }
+    }
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// The year of the event
+    #[arg(short, long, env = "AOC_YEAR", default_value_t = DEFAULT_YEAR)]
+    year: u32,
+
+    /// Only verify this day
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// The directory containing each year's `YYYY/day_N.txt` inputs
+    #[arg(long, env = "AOC_INPUT_DIR", default_value = DEFAULT_INPUT_DIR)]
+    input_dir: PathBuf,
+
+    /// The file answers are recorded in
+    #[arg(long, env = "AOC_ANSWERS", default_value = DEFAULT_ANSWERS_FILE)]
+    answers: PathBuf,
+
+    /// Record the computed answer wherever no answer has been recorded yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// The year of the event
+    #[arg(short, long, env = "AOC_YEAR", default_value_t = DEFAULT_YEAR)]
+    year: u32,
+
+    /// The day to create
+    day: u32,
+
+    /// The root of the crate to create the day in
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// The year of the event
+    #[arg(short, long, env = "AOC_YEAR", default_value_t = DEFAULT_YEAR)]
+    year: u32,
+
+    /// Only fetch this day's input (every registered day's input is fetched by default)
+    #[arg(short, long)]
+    day: Option<u32>,
+
+    /// The value of the site's `session` cookie, which is needed to download inputs
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// The site to download inputs from
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// The directory to save each year's `YYYY/day_N.txt` inputs in
+    #[arg(long, env = "AOC_INPUT_DIR", default_value = DEFAULT_INPUT_DIR)]
+    input_dir: PathBuf,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// The year of the event
+    #[arg(short, long, env = "AOC_YEAR", default_value_t = DEFAULT_YEAR)]
+    year: u32,
+
+    /// The day to submit an answer for
+    #[arg(short, long)]
+    day: u32,
+
+    /// The part to submit an answer for
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// The value of the site's `session` cookie, which is needed to submit answers
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+
+    /// The site to submit answers to
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// The directory containing each year's `YYYY/day_N.txt` inputs
+    #[arg(long, env = "AOC_INPUT_DIR", default_value = DEFAULT_INPUT_DIR)]
+    input_dir: PathBuf,
+
+    /// The file every submitted answer is recorded in
+    #[arg(long, env = "AOC_GUESSES", default_value = DEFAULT_GUESSES_FILE)]
+    guesses: PathBuf,
+
+    /// The file correct answers are recorded in
+    #[arg(long, env = "AOC_ANSWERS", default_value = DEFAULT_ANSWERS_FILE)]
+    answers: PathBuf,
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::New(args) => new(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+    }
+}
+
+/// The given day, or every registered day of the year if none was given.
+fn selected_days(year: u32, day: Option<u32>) -> Result<Vec<&'static Day>> {
+    match day {
+        Some(day) => {
+            Ok(vec![runner::get(year, day).ok_or_else(|| {
+                eyre!("{year} day {day} is not registered")
+            })?])
+        }
+        None => match runner::days(year) {
+            days if days.is_empty() => Err(eyre!("no solutions registered for year {year}")),
+            days => Ok(days),
+        },
+    }
+}
+
+fn solve(day: &Day, input: &str, parts: &[u8]) -> Result<Vec<Result<Answer, String>>> {
+    day.solve(input, parts).map_err(|e| {
+        let excerpt = e.excerpt();
+        Report::new(e).section(excerpt.header("Input:"))
+    })
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let days = selected_days(args.year, args.day)?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let source = args.input_source();
+
+    if args.format != Format::Plain {
+        let report = report::run_days(&days, &source, &parts, args.parallel);
+
+        match args.format {
+            Format::Markdown => print!("{}", report.markdown()),
+            Format::Json => print!("{}", report.json()),
+            _ => print!("{}", report.table()),
+        }
+
+        return failed_days(report.failures());
+    }
+
+    // like the other formats, a day that can't be run is reported without stopping the rest
+    let mut failed = 0;
+    for day in days {
+        let input = match source.read(day.year, day.day) {
+            Ok(input) => input,
+            Err(e) => {
+                failed += 1;
+                println!("day {}: FAILED, {}", day.day, e);
+                continue;
+            }
+        };
+
+        let answers = match day.solve(&input, &parts) {
+            Ok(answers) => answers,
+            Err(e) => {
+                failed += 1;
+                println!(
+                    "day {}: FAILED, {} at line {}, column {}\n{}",
+                    day.day,
+                    e.message,
+                    e.line,
+                    e.column,
+                    e.excerpt()
+                );
+                continue;
+            }
+        };
+
+        let mut day_failed = false;
+        for (part, answer) in parts.iter().zip(answers) {
+            match answer {
+                Ok(answer) => println!("day {} part {}: {}", day.day, part, answer),
+                Err(e) => {
+                    day_failed = true;
+                    println!("day {} part {}: FAILED, {}", day.day, part, e);
+                }
+            }
+        }
+        failed += usize::from(day_failed);
+
+        if let Some(path) = &args.render {
+            render(day, &input, path)?;
+        }
+    }
+
+    failed_days(failed)
+}
+
+fn failed_days(failed: usize) -> Result<()> {
+    match failed {
+        0 => Ok(()),
+        1 => Err(eyre!("1 day could not be run or solved")),
+        n => Err(eyre!("{n} days could not be run or solved")),
+    }
+}
+
+fn render(day: &Day, input: &str, path: &Path) -> Result<()> {
+    let image = day
+        .render(input)
+        .map_err(|e| {
+            let excerpt = e.excerpt();
+            Report::new(e).section(excerpt.header("Input:"))
+        })?
+        .ok_or_else(|| eyre!("{} day {} can't be rendered", day.year, day.day))?;
+
+    image.save(path)?;
+    println!("rendered day {} to {}", day.day, path.display());
+
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<()> {
+    let mut store = AnswerStore::load(&args.answers)?;
+    let source = InputSource::Dir(args.input_dir);
+
+    let (mut matched, mut mismatched, mut missing, mut failed) = (0, 0, 0, 0);
+
+    for day in selected_days(args.year, args.day)? {
+        let input = match source.read(day.year, day.day) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("day {}: skipped, {}", day.day, e);
+                continue;
+            }
+        };
+
+        let hash = input_hash(&input);
+        // a day that no longer parses is a regression too, but shouldn't hide the others
+        let answers = match day.solve(&input, &[1, 2]) {
+            Ok(answers) => answers,
+            Err(e) => {
+                failed += 2;
+                println!(
+                    "day {}: FAILED, {} at line {}, column {}",
+                    day.day, e.message, e.line, e.column
+                );
+                continue;
+            }
+        };
+
+        for (part, answer) in [1, 2].into_iter().zip(answers) {
+            let answer = match answer {
+                Ok(Answer::Unsolved) => {
+                    println!("day {} part {}: unsolved", day.day, part);
+                    continue;
+                }
+                Ok(answer) => answer,
+                Err(e) => {
+                    failed += 1;
+                    println!("day {} part {}: FAILED, {}", day.day, part, e);
+                    continue;
+                }
+            };
+
+            let key = AnswerKey {
+                year: day.year,
+                day: day.day,
+                part,
+                input: hash,
+            };
+            let answer = answer.to_string();
+
+            match store.check(&key, &answer) {
+                Verdict::Match => {
+                    matched += 1;
+                    println!("day {} part {}: ok", day.day, part);
+                }
+                Verdict::Mismatch { expected } => {
+                    mismatched += 1;
+                    println!(
+                        "day {} part {}: MISMATCH, expected {} but got {}",
+                        day.day, part, expected, answer
+                    );
+                }
+                Verdict::Missing if args.record => {
+                    println!("day {} part {}: recorded {}", day.day, part, answer);
+                    store.record(key, answer);
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    println!(
+                        "day {} part {}: no recorded answer (got {})",
+                        day.day, part, answer
+                    );
+                }
+            }
+        }
+    }
+
+    if args.record {
+        store.save(&args.answers)?;
+    }
+
+    println!("{matched} matched, {mismatched} mismatched, {missing} missing, {failed} failed");
+
+    match (mismatched, failed) {
+        (0, 0) => Ok(()),
+        (0, n) => Err(eyre!("{n} parts could not be solved")),
+        (n, 0) => Err(eyre!("{n} answers no longer match what was recorded")),
+        (n, m) => Err(eyre!(
+            "{n} answers no longer match what was recorded, and {m} parts could not be solved"
+        )),
+    }
+}
+
+fn new(args: NewArgs) -> Result<()> {
+    for path in scaffold::new_day(&args.root, args.year, args.day)? {
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<()> {
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => runner::days(args.year)
+            .into_iter()
+            .map(|day| day.day)
+            .collect(),
+    };
+
+    // only ask for a session once there's something to download
+    let missing: Vec<u32> = days
+        .into_iter()
+        .filter(
+            |&day| match client::cached_input(&args.input_dir, args.year, day) {
+                Some(path) => {
+                    println!("day {day}: already downloaded to {}", path.display());
+                    false
+                }
+                None => true,
+            },
+        )
+        .collect();
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let session = args.session.ok_or_else(|| {
+        eyre!("a session token is needed to download inputs (pass --session or set AOC_SESSION)")
+    })?;
+    let client = Client::new(&args.base_url, &session)?;
+
+    tokio::runtime::Runtime::new()?.block_on(async {
+        for day in missing {
+            match client::fetch_input(&client, &args.input_dir, args.year, day).await? {
+                Fetched::Cached(path) => {
+                    println!("day {day}: already downloaded to {}", path.display())
+                }
+                Fetched::Downloaded(path) => {
+                    println!("day {day}: downloaded to {}", path.display())
+                }
+            }
+        }
+
+        Ok(())
+    })
+}
+
+fn submit(args: SubmitArgs) -> Result<()> {
+    let day = selected_days(args.year, Some(args.day))?[0];
+    let input = InputSource::Dir(args.input_dir).read(day.year, day.day)?;
+
+    let answer = solve(day, &input, &[args.part])?.remove(0).map_err(|e| {
+        eyre!(
+            "day {} part {} could not be solved: {}",
+            day.day,
+            args.part,
+            e
+        )
+    })?;
+    if answer == Answer::Unsolved {
+        return Err(eyre!("day {} part {} is unsolved", day.day, args.part));
+    }
+    let answer = answer.to_string();
+
+    let key = AnswerKey {
+        year: day.year,
+        day: day.day,
+        part: args.part,
+        input: input_hash(&input),
+    };
+
+    let mut guesses = GuessLog::load(&args.guesses)?;
+    if let Err(refusal) = guesses.vet(&key, &answer) {
+        return Err(eyre!(
+            "not submitting {} for day {} part {}: {}",
+            answer,
+            day.day,
+            args.part,
+            refusal
+        ));
+    }
+
+    let session = args.session.ok_or_else(|| {
+        eyre!("a session token is needed to submit answers (pass --session or set AOC_SESSION)")
+    })?;
+    let client = Client::new(&args.base_url, &session)?;
+
+    let submitted = tokio::runtime::Runtime::new()?
+        .block_on(client.submit(day.year, day.day, args.part, &answer))?;
+
+    guesses.record(key, &answer, submitted.outcome);
+    guesses.save(&args.guesses)?;
+
+    if submitted.outcome == Outcome::Correct {
+        let mut store = AnswerStore::load(&args.answers)?;
+        store.record(key, &answer);
+        store.save(&args.answers)?;
+    }
+
+    println!(
+        "day {} part {}: {} was {}",
+        day.day, args.part, answer, submitted.outcome
+    );
+    println!("{}", submitted.message);
+
+    Ok(())
+}
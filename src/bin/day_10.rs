@@ -1,4 +0,0 @@
-fn main() {
-    let input = include_str!("../../input/day_10.txt");
-    println!("Part 1: {}", aoc::day10::solve_part_1(input));
-}
@@ -1,6 +0,0 @@
-fn main() {
-    let input = include_str!("../../input/day_6.txt");
-
-    println!("Part 1: {}", aoc::day6::solve_part_1(input));
-    println!("Part 2: {}", aoc::day6::solve_part_2(input));
-}
@@ -1,5 +0,0 @@
-fn main() {
-    let input = include_str!("../../input/day_7.txt");
-    println!("Part 1: {}", aoc::day7::solve_part_1(input));
-    println!("Part 2: {}", aoc::day7::solve_part_2(input));
-}
@@ -1,5 +0,0 @@
-fn main() {
-    let input = include_str!("../../input/day_8.txt");
-    println!("Part 1: {}", aoc::day8::solve_part_1(input));
-    println!("Part 2: {}", aoc::day8::solve_part_2(input));
-}
@@ -0,0 +1,388 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{bail, eyre, WrapErr};
+use color_eyre::Result;
+
+use crate::guesses::Outcome;
+use crate::http::{self, Url};
+use crate::input;
+
+/// Where puzzles are fetched from when no other base URL is configured.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, as the Advent of Code automation guidelines ask, so that the site's
+/// operator can tell where the traffic is coming from and who to get in touch with about it.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+",
+    env!("CARGO_PKG_REPOSITORY"),
+    "; contact via ",
+    env!("CARGO_PKG_REPOSITORY"),
+    "/issues; fetches each input once and caches it)"
+);
+
+/// Talks to the Advent of Code site (or anything that looks like it) on behalf of a logged in user.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: Url,
+    session: String,
+}
+
+impl Client {
+    /// `session` is the value of the site's `session` cookie.
+    pub fn new(base_url: &str, session: &str) -> Result<Self> {
+        Ok(Client {
+            base_url: base_url
+                .parse()
+                .wrap_err_with(|| format!("invalid base URL {base_url}"))?,
+            session: session.trim().to_string(),
+        })
+    }
+
+    /// Downloads the puzzle input for the given day.
+    pub async fn input(&self, year: u32, day: u32) -> Result<String> {
+        let url = self.base_url.join(&format!("/{year}/day/{day}/input"));
+        let response = self.send("GET", &url, None).await?;
+
+        match response.status {
+            200 => Ok(response.body),
+            404 => bail!("the input for {year} day {day} is not available yet"),
+            400 | 401 | 403 | 500 => bail!(
+                "the input for {year} day {day} could not be downloaded, check that the session token is valid ({}: {})",
+                response.status,
+                response.body.trim()
+            ),
+            status => bail!(
+                "the input for {year} day {day} could not be downloaded ({status}: {})",
+                response.body.trim()
+            ),
+        }
+    }
+
+    /// Submits an answer for one part of a day.
+    pub async fn submit(&self, year: u32, day: u32, part: u8, answer: &str) -> Result<Submitted> {
+        let url = self.base_url.join(&format!("/{year}/day/{day}/answer"));
+        let body = format!("level={part}&answer={}", form_encode(answer));
+        let response = self.send("POST", &url, Some(&body)).await?;
+
+        if response.status != 200 {
+            bail!(
+                "the answer for {year} day {day} part {part} could not be submitted ({}: {})",
+                response.status,
+                response.body.trim()
+            );
+        }
+
+        let message = article_text(&response.body);
+        let outcome = parse_outcome(&message).ok_or_else(|| {
+            eyre!("could not make sense of the response to the answer for {year} day {day} part {part}: {message}")
+        })?;
+
+        Ok(Submitted { outcome, message })
+    }
+
+    async fn send(&self, method: &str, url: &Url, body: Option<&str>) -> Result<http::Response> {
+        let cookie = format!("session={}", self.session);
+        let mut headers = vec![("User-Agent", USER_AGENT), ("Cookie", cookie.as_str())];
+
+        if body.is_some() {
+            headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+        }
+
+        http::send(method, url, &headers, body)
+            .await
+            .wrap_err_with(|| format!("request to {}{} failed", url.host, url.path))
+    }
+}
+
+/// The site's verdict on a submitted answer.
+#[derive(Debug, PartialEq)]
+pub struct Submitted {
+    pub outcome: Outcome,
+    /// What the site said, as plain text, e.g. how long to wait before trying again.
+    pub message: String,
+}
+
+/// Works out the outcome of a submission from the text of the site's response.
+fn parse_outcome(message: &str) -> Option<Outcome> {
+    if message.contains("That's the right answer") {
+        Some(Outcome::Correct)
+    } else if message.contains("You gave an answer too recently") {
+        Some(Outcome::RateLimited)
+    } else if message.contains("You don't seem to be solving the right level") {
+        Some(Outcome::WrongLevel)
+    } else if message.contains("your answer is too high") {
+        Some(Outcome::TooHigh)
+    } else if message.contains("your answer is too low") {
+        Some(Outcome::TooLow)
+    } else if message.contains("That's not the right answer") {
+        Some(Outcome::Wrong)
+    } else {
+        None
+    }
+}
+
+/// The text of the page's `<article>` (the whole page, if there isn't one), without its markup.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Encodes a value for an `application/x-www-form-urlencoded` body.
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => {
+                (b as char).to_string()
+            }
+            b' ' => "+".to_string(),
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Where a day's input came from when it was fetched.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    /// The input had been downloaded already, so the site wasn't contacted.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// The given day's input inside `dir`, if it has been downloaded already. An empty file (like the
+/// one `aoc new` creates) doesn't count.
+pub fn cached_input(dir: &Path, year: u32, day: u32) -> Option<PathBuf> {
+    let path = input::input_path(dir, year, day);
+
+    match std::fs::metadata(&path) {
+        Ok(metadata) if metadata.is_file() && metadata.len() > 0 => Some(path),
+        _ => None,
+    }
+}
+
+/// Downloads the given day's input into `dir`, unless it's there already. Inputs never change, so
+/// once an input has been downloaded it's never downloaded again.
+pub async fn fetch_input(client: &Client, dir: &Path, year: u32, day: u32) -> Result<Fetched> {
+    if let Some(path) = cached_input(dir, year, day) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = client.input(year, day).await?;
+
+    let path = input::input_path(dir, year, day);
+    let parent = path.parent().unwrap_or(dir);
+    std::fs::create_dir_all(parent)
+        .wrap_err_with(|| format!("could not create {}", parent.display()))?;
+
+    // write to a temporary file first, so an interrupted write can't leave a partial input behind
+    // that would then be mistaken for a cached one
+    let partial = path.with_extension("txt.partial");
+    std::fs::write(&partial, input)
+        .wrap_err_with(|| format!("could not write {}", partial.display()))?;
+    std::fs::rename(&partial, &path)
+        .wrap_err_with(|| format!("could not write {}", path.display()))?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// A stand-in for the site that answers every request with the same response, and keeps every
+    /// request it receives.
+    struct StubServer {
+        base_url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl StubServer {
+        async fn start(status: u16, body: &'static str) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let received = requests.clone();
+            tokio::spawn(async move {
+                loop {
+                    let (mut stream, _) = listener.accept().await.unwrap();
+
+                    let mut request = Vec::new();
+                    let mut buf = [0; 1024];
+                    while !is_complete(&request) {
+                        let n = stream.read(&mut buf).await.unwrap();
+                        if n == 0 {
+                            break;
+                        }
+                        request.extend_from_slice(&buf[..n]);
+                    }
+                    received
+                        .lock()
+                        .unwrap()
+                        .push(String::from_utf8(request).unwrap());
+
+                    let response = format!(
+                        "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\n\r\n{body}",
+                        body.len()
+                    );
+                    stream.write_all(response.as_bytes()).await.unwrap();
+                    stream.shutdown().await.unwrap();
+                }
+            });
+
+            StubServer { base_url, requests }
+        }
+
+        fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    /// Whether a request's headers, and the body its `Content-Length` announces, have all arrived.
+    fn is_complete(request: &[u8]) -> bool {
+        let text = String::from_utf8_lossy(request);
+        let Some((head, body)) = text.split_once("\r\n\r\n") else {
+            return false;
+        };
+
+        let length = head
+            .lines()
+            .find_map(|line| line.strip_prefix("Content-Length: "))
+            .and_then(|length| length.parse().ok())
+            .unwrap_or(0);
+
+        body.len() >= length
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("aoc-client-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn test_fetch_input() {
+        let server = StubServer::start(200, "1abc2\npqr3stu8vwx\n").await;
+        let client = Client::new(&server.base_url, "abc123").unwrap();
+        let dir = temp_dir();
+
+        let fetched = fetch_input(&client, &dir, 2023, 1).await.unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(dir.join("2023/day_1.txt")));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("2023/day_1.txt")).unwrap(),
+            "1abc2\npqr3stu8vwx\n"
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2023/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc123\r\n"));
+        assert!(requests[0].contains(&format!("\r\nUser-Agent: {USER_AGENT}\r\n")));
+        // the stub isn't on port 80, so the port has to be part of the Host header
+        let authority = server.base_url.trim_start_matches("http://");
+        assert!(requests[0].contains(&format!("\r\nHost: {authority}\r\n")));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fetch_input_is_cached() {
+        let server = StubServer::start(200, "new input").await;
+        let client = Client::new(&server.base_url, "abc123").unwrap();
+        let dir = temp_dir();
+
+        std::fs::create_dir_all(dir.join("2023")).unwrap();
+        std::fs::write(dir.join("2023/day_2.txt"), "old input").unwrap();
+        // an empty placeholder, as `aoc new` leaves, isn't a cached input
+        std::fs::write(dir.join("2023/day_3.txt"), "").unwrap();
+
+        let fetched = fetch_input(&client, &dir, 2023, 2).await.unwrap();
+        assert_eq!(fetched, Fetched::Cached(dir.join("2023/day_2.txt")));
+        assert!(server.requests().is_empty());
+
+        let fetched = fetch_input(&client, &dir, 2023, 3).await.unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(dir.join("2023/day_3.txt")));
+        assert_eq!(server.requests().len(), 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fetch_locked_input() {
+        let server = StubServer::start(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        )
+        .await;
+        let client = Client::new(&server.base_url, "abc123").unwrap();
+        let dir = temp_dir();
+
+        let err = fetch_input(&client, &dir, 2023, 25).await.unwrap_err();
+        assert!(err.to_string().contains("not available yet"));
+        assert!(!dir.join("2023/day_25.txt").exists());
+    }
+
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. <a href=\"/2023/day/5\">[Return to Day 5]</a></p></article>\n</main>";
+
+    #[test]
+    fn test_parse_outcome() {
+        let message = article_text(TOO_HIGH);
+        assert!(message.starts_with("That's not the right answer; your answer is too high. If"));
+        assert_eq!(parse_outcome(&message), Some(Outcome::TooHigh));
+
+        let cases = [
+            ("That's the right answer! You are one gold star closer.", Outcome::Correct),
+            ("That's not the right answer. If you're stuck, ...", Outcome::Wrong),
+            ("That's not the right answer; your answer is too low.", Outcome::TooLow),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 38s left to wait.", Outcome::RateLimited),
+            ("You don't seem to be solving the right level. Did you already complete it?", Outcome::WrongLevel),
+        ];
+        for (message, outcome) in cases {
+            assert_eq!(parse_outcome(message), Some(outcome), "{message}");
+        }
+
+        assert_eq!(parse_outcome("Something else entirely"), None);
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(form_encode("1234"), "1234");
+        assert_eq!(form_encode("a b&c=d"), "a+b%26c%3Dd");
+    }
+
+    #[tokio::test]
+    async fn test_submit() {
+        let server = StubServer::start(200, TOO_HIGH).await;
+        let client = Client::new(&server.base_url, "abc123").unwrap();
+
+        let submitted = client.submit(2023, 5, 2, "4252267481").await.unwrap();
+        assert_eq!(submitted.outcome, Outcome::TooHigh);
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2023/day/5/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nContent-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=4252267481"));
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+
+use nom::error::ErrorKind;
+
+/// A puzzle input that couldn't be parsed, along with where in the input it went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The year of the event, once it's known. Parsers only know their day, so the year is filled
+    /// in by whatever ran them.
+    pub year: Option<u32>,
+    pub day: u32,
+    /// 1-based line number within the input.
+    pub line: usize,
+    /// 1-based column (in characters) within the line.
+    pub column: usize,
+    /// The full text of the offending line.
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error pointing at `at`, which must be a sub-slice of `input` (e.g. what a nom
+    /// parser had left over when it failed). The line and column are worked out from where `at`
+    /// starts within `input`.
+    pub fn new(day: u32, input: &str, at: &str, message: impl Into<String>) -> Self {
+        // nom only ever hands back slices of its input, so the pointer difference is the byte
+        // offset of the failure. Anything else is clamped to the end of the input.
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len() && input.is_char_boundary(*offset))
+            .unwrap_or(input.len());
+
+        let line_start = input[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = input[offset..]
+            .find('\n')
+            .map(|i| i + offset)
+            .unwrap_or(input.len());
+
+        Self {
+            year: None,
+            day,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Converts a failed nom parse of (some sub-slice of) `input` into a located error.
+    pub fn from_nom(day: u32, input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                Self::new(day, input, e.input, describe(e.code))
+            }
+            nom::Err::Incomplete(_) => {
+                Self::new(day, input, &input[input.len()..], "unexpected end of input")
+            }
+        }
+    }
+
+    /// The same error, for the given year's puzzle.
+    pub fn in_year(self, year: u32) -> Self {
+        Self {
+            year: Some(year),
+            ..self
+        }
+    }
+
+    /// The offending line with a caret under the column, in the style of rustc's diagnostics.
+    pub fn excerpt(&self) -> String {
+        let gutter = self.line.to_string().len();
+
+        format!(
+            "{:gutter$} |\n{} | {}\n{:gutter$} | {:>col$}",
+            "",
+            self.line,
+            self.snippet,
+            "",
+            "^",
+            col = self.column
+        )
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Eof => "unexpected trailing input".to_string(),
+        ErrorKind::Tag => "unexpected text".to_string(),
+        ErrorKind::Digit => "expected a number".to_string(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "expected whitespace".to_string(),
+        ErrorKind::Char => "unexpected character".to_string(),
+        ErrorKind::CrLf => "expected a newline".to_string(),
+        ErrorKind::TooLarge => "number out of range".to_string(),
+        ErrorKind::TakeWhileMN => "unexpected number of characters".to_string(),
+        kind => format!("could not parse input ({})", kind.description()),
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(year) = self.year {
+            write!(f, "{year} ")?;
+        }
+
+        write!(
+            f,
+            "day {}: {} at line {}, column {}",
+            self.day, self.message, self.line, self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_of_sub_slice() {
+        let input = "Game 1: 3 blue\nGame 2: 4 grean, 1 red\n";
+        let at = &input[input.find("grean").unwrap()..];
+        let err = ParseError::new(2, input, at, "unknown colour");
+
+        assert_eq!(err.line, 2);
+        assert_eq!(err.column, 11);
+        assert_eq!(err.snippet, "Game 2: 4 grean, 1 red");
+    }
+
+    #[test]
+    fn test_location_at_end_of_input() {
+        let input = "abc";
+        let err = ParseError::new(1, input, &input[3..], "unexpected end of input");
+
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(err.snippet, "abc");
+    }
+
+    #[test]
+    fn test_display() {
+        let input = "1 2\n3 x 5";
+        let err = ParseError::new(9, input, &input[6..], "expected a number");
+
+        assert_eq!(
+            err.to_string(),
+            "day 9: expected a number at line 2, column 3"
+        );
+        assert_eq!(
+            err.clone().in_year(2023).to_string(),
+            "2023 day 9: expected a number at line 2, column 3"
+        );
+        assert_eq!(err.excerpt(), "  |\n2 | 3 x 5\n  |   ^");
+    }
+}
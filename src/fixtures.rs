@@ -0,0 +1,205 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, WrapErr};
+use color_eyre::Result;
+
+/// The directory example fixtures live in, relative to the crate root.
+pub const DEFAULT_EXAMPLES_DIR: &str = "examples";
+
+/// The answer an example is expected to produce for one part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected {
+    pub part: u8,
+    pub answer: String,
+    /// The answer is known, but the solution doesn't produce it yet.
+    pub pending: bool,
+}
+
+/// One example input from the puzzle text, along with the answers it should produce.
+///
+/// Fixtures live at `examples/YYYY/day_N/<name>.txt`, with the expected answers next to the input in
+/// `<name>.answers`, one part per line:
+///
+/// ```text
+/// part 1: 35
+/// part 2: 46 (pending)
+/// ```
+///
+/// Parts that aren't listed aren't checked, since some examples only apply to one part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fixture {
+    pub year: u32,
+    pub day: u32,
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<Expected>,
+}
+
+/// The directory holding the given day's fixtures.
+pub fn day_dir(root: &Path, year: u32, day: u32) -> PathBuf {
+    root.join(year.to_string()).join(format!("day_{day}"))
+}
+
+/// Finds every fixture for every day of every year under `root`, ordered by year, day and then
+/// name.
+pub fn discover(root: &Path) -> Result<Vec<Fixture>> {
+    let mut fixtures = Vec::new();
+
+    for year_dir in read_dir(root)? {
+        let Some(year) = dir_number(&year_dir, "") else {
+            continue;
+        };
+
+        for day_dir in read_dir(&year_dir)? {
+            if let Some(day) = dir_number(&day_dir, "day_") {
+                fixtures.extend(discover_day(&day_dir, year, day)?);
+            }
+        }
+    }
+
+    fixtures.sort_by(|a, b| (a.year, a.day, &a.name).cmp(&(b.year, b.day, &b.name)));
+
+    Ok(fixtures)
+}
+
+/// The number in the name of a directory named `<prefix>N`, if `path` is one.
+fn dir_number(path: &Path, prefix: &str) -> Option<u32> {
+    if !path.is_dir() {
+        return None;
+    }
+
+    path.file_name()?
+        .to_str()?
+        .strip_prefix(prefix)?
+        .parse()
+        .ok()
+}
+
+fn discover_day(dir: &Path, year: u32, day: u32) -> Result<Vec<Fixture>> {
+    let mut fixtures = Vec::new();
+
+    for path in read_dir(dir)? {
+        if path.extension().and_then(|ext| ext.to_str()) != Some("txt") {
+            continue;
+        }
+
+        let name = path
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| eyre!("{} is not a valid fixture name", path.display()))?
+            .to_string();
+
+        let input = std::fs::read_to_string(&path)
+            .wrap_err_with(|| format!("could not read {}", path.display()))?;
+
+        let answers_path = path.with_extension("answers");
+        let expected = match answers_path.exists() {
+            true => std::fs::read_to_string(&answers_path)
+                .wrap_err_with(|| format!("could not read {}", answers_path.display()))
+                .and_then(|answers| parse_answers(&answers))
+                .wrap_err_with(|| format!("invalid answers in {}", answers_path.display()))?,
+            false => Vec::new(),
+        };
+
+        fixtures.push(Fixture {
+            year,
+            day,
+            name,
+            input,
+            expected,
+        });
+    }
+
+    Ok(fixtures)
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+    std::fs::read_dir(dir)
+        .wrap_err_with(|| format!("could not read {}", dir.display()))?
+        .map(|entry| Ok(entry?.path()))
+        .collect()
+}
+
+/// Parses the lines of an `.answers` file, e.g. `part 1: 35` or `part 2: 46 (pending)`. Blank
+/// lines and lines starting with `#` are ignored.
+pub fn parse_answers(input: &str) -> Result<Vec<Expected>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(idx, line)| {
+            let bad_line = || eyre!("line {} is not `part N: answer`", idx + 1);
+
+            let (part, answer) = line
+                .strip_prefix("part ")
+                .and_then(|line| line.split_once(':'))
+                .ok_or_else(bad_line)?;
+
+            let part = part.trim().parse::<u8>().map_err(|_| bad_line())?;
+            let answer = answer.trim();
+
+            let (answer, pending) = match answer.strip_suffix("(pending)") {
+                Some(answer) => (answer.trim(), true),
+                None => (answer, false),
+            };
+
+            if answer.is_empty() {
+                return Err(bad_line());
+            }
+
+            Ok(Expected {
+                part,
+                answer: answer.to_string(),
+                pending,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("# from the puzzle text\npart 1: 35\n\npart 2: 46 (pending)\n");
+
+        assert_eq!(
+            answers.unwrap(),
+            vec![
+                Expected {
+                    part: 1,
+                    answer: "35".to_string(),
+                    pending: false
+                },
+                Expected {
+                    part: 2,
+                    answer: "46".to_string(),
+                    pending: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_bad_answers() {
+        assert!(parse_answers("part one: 35").is_err());
+        assert!(parse_answers("part 1 35").is_err());
+        assert!(parse_answers("part 1: (pending)").is_err());
+    }
+
+    #[test]
+    fn test_discover() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_EXAMPLES_DIR);
+        let fixtures = discover(&root).unwrap();
+
+        let day_5 = fixtures
+            .iter()
+            .find(|f| (f.year, f.day) == (2023, 5))
+            .unwrap();
+        assert_eq!(day_5.name, "example");
+        assert!(day_5.input.starts_with("seeds: 79 14 55 13"));
+        assert_eq!(day_5.expected.len(), 2);
+    }
+}
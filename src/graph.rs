@@ -0,0 +1,535 @@
+//! Searches over graphs that are only described by how to get from one node to the next, so that
+//! they work just as well on a map of named nodes as on the positions of a `Grid`.
+//!
+//! Anything that implements `Graph` (or `WeightedGraph`, where edges have a cost) can be searched,
+//! and that includes closures, e.g. `|&pos: &Point| grid.neighbours(pos)`. Everything is iterative,
+//! so a search never runs out of stack however big the graph is.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A graph whose edges all cost the same, given by the nodes each node leads to.
+pub trait Graph<N> {
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = N>;
+}
+
+impl<N, F, I> Graph<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = N> {
+        self(node)
+    }
+}
+
+/// A graph given by the nodes each node leads to, along with what it costs to get to each of them.
+pub trait WeightedGraph<N> {
+    fn edges(&self, node: &N) -> impl IntoIterator<Item = (N, u64)>;
+}
+
+impl<N, F, I> WeightedGraph<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    fn edges(&self, node: &N) -> impl IntoIterator<Item = (N, u64)> {
+        self(node)
+    }
+}
+
+/// What a search found out about the nodes it reached: what it cost to get to each of them from
+/// the start, and the node it got there from, which is enough to reconstruct the path.
+#[derive(Debug, Clone)]
+pub struct Paths<N> {
+    costs: HashMap<N, u64>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Paths<N> {
+    fn new(start: N) -> Self {
+        Self {
+            costs: HashMap::from([(start, 0)]),
+            parents: HashMap::new(),
+        }
+    }
+
+    /// The cost of getting to `node` (the number of steps, for an unweighted graph), or `None` if
+    /// it wasn't reached.
+    pub fn cost(&self, node: &N) -> Option<u64> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// Every node that was reached, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.costs.keys()
+    }
+
+    /// The path from the start to `goal`, including both, or `None` if it wasn't reached.
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        if !self.contains(goal) {
+            return None;
+        }
+
+        let mut path = vec![goal.clone()];
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Records getting to `node` from `parent` for `cost`, unless it was already reached for no
+    /// more than that. Returns whether it was recorded.
+    fn relax(&mut self, node: &N, parent: &N, cost: u64) -> bool {
+        if self.cost(node).is_some_and(|known| known <= cost) {
+            return false;
+        }
+
+        self.costs.insert(node.clone(), cost);
+        self.parents.insert(node.clone(), parent.clone());
+        true
+    }
+}
+
+/// A breadth-first search, made by `bfs`.
+pub struct Bfs<'g, N, G> {
+    graph: &'g G,
+    queue: VecDeque<N>,
+    paths: Paths<N>,
+}
+
+/// Visits every node reachable from `start` (including `start` itself) in order of how many steps
+/// away it is, yielding it along with that number of steps.
+pub fn bfs<N: Clone + Eq + Hash, G: Graph<N>>(graph: &G, start: N) -> Bfs<'_, N, G> {
+    Bfs {
+        graph,
+        queue: VecDeque::from([start.clone()]),
+        paths: Paths::new(start),
+    }
+}
+
+impl<N: Clone + Eq + Hash, G> Bfs<'_, N, G> {
+    /// What the search has found so far, which includes the paths to every node it has yielded.
+    pub fn paths(&self) -> &Paths<N> {
+        &self.paths
+    }
+}
+
+impl<N: Clone + Eq + Hash, G: Graph<N>> Bfs<'_, N, G> {
+    /// Finishes the search, and gives the paths to every node it reached.
+    pub fn into_paths(mut self) -> Paths<N> {
+        self.by_ref().for_each(drop);
+        self.paths
+    }
+}
+
+impl<N: Clone + Eq + Hash, G: Graph<N>> Iterator for Bfs<'_, N, G> {
+    type Item = (N, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        let steps = self.paths.costs[&node];
+
+        for next in self.graph.neighbours(&node) {
+            if !self.paths.contains(&next) {
+                self.paths.relax(&next, &node, steps + 1);
+                self.queue.push_back(next);
+            }
+        }
+
+        Some((node, steps))
+    }
+}
+
+/// A depth-first search, made by `dfs`.
+pub struct Dfs<'g, N, G> {
+    graph: &'g G,
+    /// Nodes still to visit, along with the node they were found from.
+    stack: Vec<(N, Option<N>)>,
+    paths: Paths<N>,
+}
+
+/// Visits every node reachable from `start` (including `start` itself) depth first, following a
+/// node's neighbours in the order the graph gives them. Each node is yielded along with its depth
+/// in the search, which isn't necessarily the shortest distance to it.
+pub fn dfs<N: Clone + Eq + Hash, G: Graph<N>>(graph: &G, start: N) -> Dfs<'_, N, G> {
+    Dfs {
+        graph,
+        stack: vec![(start.clone(), None)],
+        paths: Paths {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+        },
+    }
+}
+
+impl<N: Clone + Eq + Hash, G> Dfs<'_, N, G> {
+    /// What the search has visited so far, with the path the search took to each node.
+    pub fn paths(&self) -> &Paths<N> {
+        &self.paths
+    }
+}
+
+impl<N: Clone + Eq + Hash, G: Graph<N>> Dfs<'_, N, G> {
+    /// Finishes the search, and gives the paths to every node it reached.
+    pub fn into_paths(mut self) -> Paths<N> {
+        self.by_ref().for_each(drop);
+        self.paths
+    }
+}
+
+impl<N: Clone + Eq + Hash, G: Graph<N>> Iterator for Dfs<'_, N, G> {
+    type Item = (N, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, parent) = self.stack.pop()?;
+            if self.paths.contains(&node) {
+                continue;
+            }
+
+            let depth = match parent {
+                Some(parent) => {
+                    let depth = self.paths.costs[&parent] + 1;
+                    self.paths.parents.insert(node.clone(), parent);
+                    depth
+                }
+                None => 0,
+            };
+            self.paths.costs.insert(node.clone(), depth);
+
+            // pushed in reverse, so that the first neighbour is the first one popped
+            let unvisited: Vec<N> = self
+                .graph
+                .neighbours(&node)
+                .into_iter()
+                .filter(|next| !self.paths.contains(next))
+                .collect();
+            self.stack.extend(
+                unvisited
+                    .into_iter()
+                    .rev()
+                    .map(|next| (next, Some(node.clone()))),
+            );
+
+            return Some((node, depth));
+        }
+    }
+}
+
+/// A node waiting in a best-first search's queue, which is ordered so that the `BinaryHeap` pops
+/// the lowest `priority` first.
+struct Queued<N> {
+    priority: u64,
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Queued<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Queued<N> {}
+
+impl<N> PartialOrd for Queued<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Queued<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Searches outwards from `start` in order of cost plus `heuristic`, until it settles a node that
+/// is a `goal` (which it returns) or runs out of nodes.
+fn best_first<N, G>(
+    graph: &G,
+    start: N,
+    mut goal: impl FnMut(&N) -> bool,
+    mut heuristic: impl FnMut(&N) -> u64,
+) -> (Paths<N>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    G: WeightedGraph<N>,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: 0,
+        node: start,
+    }]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // a cheaper way here was found after this one was queued
+        if paths.cost(&node).is_some_and(|known| known < cost) {
+            continue;
+        }
+
+        if goal(&node) {
+            return (paths, Some(node));
+        }
+
+        for (next, weight) in graph.edges(&node) {
+            let next_cost = cost + weight;
+            if paths.relax(&next, &node, next_cost) {
+                queue.push(Queued {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    (paths, None)
+}
+
+/// The cheapest path from `start` to the nearest node that is a `goal`, along with its cost, or
+/// `None` if no goal can be reached.
+pub fn dijkstra<N, G>(graph: &G, start: N, goal: impl FnMut(&N) -> bool) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    G: WeightedGraph<N>,
+{
+    astar(graph, start, goal, |_| 0)
+}
+
+/// The cheapest paths from `start` to every node reachable from it.
+pub fn dijkstra_all<N, G>(graph: &G, start: N) -> Paths<N>
+where
+    N: Clone + Eq + Hash,
+    G: WeightedGraph<N>,
+{
+    best_first(graph, start, |_| false, |_| 0).0
+}
+
+/// Like `dijkstra`, but guided towards the goal by `heuristic`, an estimate of the cost from a node
+/// to the nearest goal. The path is only guaranteed to be the cheapest if the estimate never
+/// overestimates, e.g. the manhattan distance on a grid.
+pub fn astar<N, G>(
+    graph: &G,
+    start: N,
+    goal: impl FnMut(&N) -> bool,
+    heuristic: impl FnMut(&N) -> u64,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    G: WeightedGraph<N>,
+{
+    let (paths, found) = best_first(graph, start, goal, heuristic);
+    let found = found?;
+
+    Some((paths.path_to(&found)?, paths.cost(&found)?))
+}
+
+/// Splits `nodes` (and everything reachable from them) into groups of nodes that are connected to
+/// each other. That's only meaningful if every edge can be followed both ways.
+pub fn connected_components<N, G>(graph: &G, nodes: impl IntoIterator<Item = N>) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    G: Graph<N>,
+{
+    let mut seen: HashMap<N, usize> = HashMap::new();
+    let mut components = Vec::new();
+
+    for node in nodes {
+        if seen.contains_key(&node) {
+            continue;
+        }
+
+        let component: Vec<N> = bfs(graph, node).map(|(node, _)| node).collect();
+        for node in &component {
+            seen.insert(node.clone(), components.len());
+        }
+        components.push(component);
+    }
+
+    components
+}
+
+/// Orders `nodes` and everything reachable from them so that every node comes before all of the
+/// nodes it leads to. If that's impossible, returns a node that is part of a cycle instead.
+pub fn toposort<N, G>(graph: &G, nodes: impl IntoIterator<Item = N>) -> Result<Vec<N>, N>
+where
+    N: Clone + Eq + Hash,
+    G: Graph<N>,
+{
+    #[derive(PartialEq)]
+    enum Mark {
+        InProgress,
+        Done,
+    }
+
+    let neighbours = |node: &N| -> std::vec::IntoIter<N> {
+        let neighbours: Vec<N> = graph.neighbours(node).into_iter().collect();
+        neighbours.into_iter()
+    };
+
+    let mut marks: HashMap<N, Mark> = HashMap::new();
+    let mut order = Vec::new();
+
+    for root in nodes {
+        if marks.contains_key(&root) {
+            continue;
+        }
+
+        marks.insert(root.clone(), Mark::InProgress);
+        let mut stack = vec![(root.clone(), neighbours(&root))];
+
+        // a node is done, and goes after everything it leads to, once all of those are done
+        while let Some((_, remaining)) = stack.last_mut() {
+            match remaining.next() {
+                Some(next) => match marks.get(&next) {
+                    Some(Mark::InProgress) => return Err(next),
+                    Some(Mark::Done) => {}
+                    None => {
+                        marks.insert(next.clone(), Mark::InProgress);
+                        let remaining = neighbours(&next);
+                        stack.push((next, remaining));
+                    }
+                },
+                None => {
+                    let (node, _) = stack.pop().expect("Expected the node being finished");
+                    marks.insert(node.clone(), Mark::Done);
+                    order.push(node);
+                }
+            }
+        }
+    }
+
+    order.reverse();
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::point::Point;
+
+    fn edges(node: &u32) -> Vec<u32> {
+        match node {
+            1 => vec![2, 3],
+            2 => vec![4],
+            3 => vec![4],
+            4 => vec![5],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let visited: Vec<(u32, u64)> = bfs(&edges, 1).collect();
+        assert_eq!(visited, vec![(1, 0), (2, 1), (3, 1), (4, 2), (5, 3)]);
+
+        let paths = bfs(&edges, 1).into_paths();
+        assert_eq!(paths.path_to(&5), Some(vec![1, 2, 4, 5]));
+        assert_eq!(paths.path_to(&1), Some(vec![1]));
+        assert_eq!(paths.path_to(&6), None);
+
+        // stopping early still leaves the paths to what was found
+        let mut search = bfs(&edges, 1);
+        assert_eq!(search.find(|&(node, _)| node == 3), Some((3, 1)));
+        assert_eq!(search.paths().path_to(&3), Some(vec![1, 3]));
+    }
+
+    #[test]
+    fn test_dfs() {
+        let visited: Vec<u32> = dfs(&edges, 1).map(|(node, _)| node).collect();
+        assert_eq!(visited, vec![1, 2, 4, 5, 3]);
+
+        let paths = dfs(&edges, 1).into_paths();
+        assert_eq!(paths.path_to(&3), Some(vec![1, 3]));
+        assert_eq!(paths.cost(&5), Some(3));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let weighted = |node: &char| -> Vec<(char, u64)> {
+            match node {
+                'a' => vec![('b', 7), ('c', 9), ('f', 14)],
+                'b' => vec![('c', 10), ('d', 15)],
+                'c' => vec![('d', 11), ('f', 2)],
+                'd' => vec![('e', 6)],
+                'f' => vec![('e', 9)],
+                _ => vec![],
+            }
+        };
+
+        assert_eq!(
+            dijkstra(&weighted, 'a', |&node| node == 'e'),
+            Some((vec!['a', 'c', 'f', 'e'], 20))
+        );
+        assert_eq!(dijkstra(&weighted, 'e', |&node| node == 'a'), None);
+
+        let paths = dijkstra_all(&weighted, 'a');
+        assert_eq!(paths.cost(&'d'), Some(20));
+        assert_eq!(paths.cost(&'f'), Some(11));
+
+        // the same path, through a maze on a grid
+        let grid = Grid::parse(0, "..#.\n.##.\n....", |c| Ok(c == '#')).unwrap();
+        let open = |&pos: &Point| -> Vec<(Point, u64)> {
+            grid.neighbours(pos)
+                .filter(|&next| !grid[next])
+                .map(|next| (next, 1))
+                .collect()
+        };
+        let goal = Point::new(0, 3);
+
+        let (path, cost) = astar(
+            &open,
+            Point::ORIGIN,
+            |&pos| pos == goal,
+            |pos| pos.manhattan(goal),
+        )
+        .unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path.len(), 8);
+        assert_eq!(
+            dijkstra(&open, Point::ORIGIN, |&pos| pos == goal),
+            Some((path, cost))
+        );
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let undirected = |node: &u32| match node {
+            1 => vec![2],
+            2 => vec![1],
+            3 => vec![4, 5],
+            4 => vec![3],
+            5 => vec![3],
+            _ => vec![],
+        };
+
+        let mut components = connected_components(&undirected, 1..=6);
+        components.iter_mut().for_each(|component| component.sort());
+        assert_eq!(components, vec![vec![1, 2], vec![3, 4, 5], vec![6]]);
+    }
+
+    #[test]
+    fn test_toposort() {
+        assert_eq!(toposort(&edges, [1]), Ok(vec![1, 3, 2, 4, 5]));
+        assert_eq!(toposort(&edges, [4, 1]), Ok(vec![1, 3, 2, 4, 5]));
+
+        let cyclic = |node: &u32| match node {
+            1 => vec![2],
+            2 => vec![3],
+            3 => vec![1],
+            _ => vec![],
+        };
+        assert!(matches!(toposort(&cyclic, [1]), Err(1..=3)));
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::point::{Direction, Point};
+
+/// A dense, rectangular 2D grid, stored row by row. Cells are addressed by `Point`s, with `(0, 0)` in
+/// the top left corner; anything outside the grid, negative points included, just isn't in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid with a line per row and a character per cell, turning each character into a
+    /// cell with `cell`, which returns a message explaining what's wrong with characters it doesn't
+    /// accept. Every line must be the same length.
+    pub fn parse(
+        day: u32,
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let mut line_width = 0;
+
+            for (idx, c) in line.char_indices() {
+                let value = cell(c)
+                    .map_err(|message| ParseError::new(day, input, &line[idx..], message))?;
+                cells.push(value);
+                line_width += 1;
+            }
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    let at = &line[line
+                        .char_indices()
+                        .nth(width)
+                        .map_or(line.len(), |(idx, _)| idx)..];
+                    let message = format!("expected every line to be {width} characters long");
+                    return Err(ParseError::new(day, input, at, message));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.index_of(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Replaces a cell, returning what was there before, or `None` (leaving the grid untouched) if
+    /// `pos` is outside the grid.
+    pub fn set(&mut self, pos: Point, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// The cells above, right of, below and left of `pos` that are inside the grid.
+    pub fn neighbours(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .map(move |direction| pos + direction)
+            .filter(|&pos| self.contains(pos))
+    }
+
+    /// The cells around `pos`, diagonals included, that are inside the grid.
+    pub fn surrounding(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| pos + direction)
+            .filter(|&pos| self.contains(pos))
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, which an empty grid's width is
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let cells = match col < self.width {
+            true => &self.cells[col..],
+            false => &[],
+        };
+
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (Point::from((idx / self.width, idx % self.width)), cell))
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Where `pos` is in `cells`, if it's inside the grid.
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        let (row, col) = pos.to_indices()?;
+        (row < self.height && col < self.width).then_some(row * self.width + col)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{pos:?} is outside of a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of a {width}x{height} grid"))
+    }
+}
+
+/// Prints a row per line, with the cells of each row next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(0, "abc\ndef", Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(1, 2)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse(0, "abc\nde", Ok).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = Grid::parse(0, "abc\ndefg", Ok).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+
+        let err = Grid::parse(0, "..\n.x", |c| match c {
+            '.' => Ok(()),
+            c => Err(format!("unexpected '{c}'")),
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.message, "unexpected 'x'");
+    }
+
+    #[test]
+    fn test_set() {
+        let mut grid = grid();
+
+        assert_eq!(grid.set(Point::new(0, 1), 'x'), Some('b'));
+        assert_eq!(grid.set(Point::new(5, 5), 'x'), None);
+        grid[Point::new(1, 0)] = 'y';
+
+        assert_eq!(grid.to_string(), "axc\nyef\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(
+            grid.neighbours(Point::ORIGIN).collect::<Vec<_>>(),
+            vec![Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours(Point::new(1, 1)).count(), 4);
+        assert_eq!(
+            grid.surrounding(Point::new(0, 2)).collect::<Vec<_>>(),
+            vec![Point::new(1, 2), Point::new(1, 1), Point::new(0, 1)]
+        );
+        assert_eq!(grid.surrounding(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.row(2), None);
+
+        let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.column(3).count(), 0);
+
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Point::new(1, 1)));
+    }
+}
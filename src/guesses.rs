@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use color_eyre::Result;
+
+use crate::answers::AnswerKey;
+use crate::records::{self, Records};
+
+/// Where submitted guesses are kept when no other file is configured.
+pub const DEFAULT_GUESSES_FILE: &str = "guesses.txt";
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the last wrong answer, so it wasn't checked.
+    RateLimited,
+    /// The part was already solved (or isn't unlocked yet), so it wasn't checked.
+    WrongLevel,
+}
+
+impl Outcome {
+    fn tag(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::RateLimited => "rate-limited",
+            Outcome::WrongLevel => "wrong-level",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Self> {
+        [
+            Outcome::Correct,
+            Outcome::Wrong,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::RateLimited,
+            Outcome::WrongLevel,
+        ]
+        .into_iter()
+        .find(|outcome| outcome.tag() == tag)
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.tag())
+    }
+}
+
+/// One answer that was submitted, and what the site made of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guess {
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Why an answer shouldn't be submitted.
+#[derive(Debug, PartialEq)]
+pub enum Refusal {
+    Solved { answer: String },
+    KnownWrong { outcome: Outcome },
+    NotBelow { too_high: i128 },
+    NotAbove { too_low: i128 },
+}
+
+impl std::fmt::Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "this part was already solved with {answer}"),
+            Refusal::KnownWrong { outcome } => {
+                write!(f, "this answer was already submitted and was {outcome}")
+            }
+            Refusal::NotBelow { too_high } => {
+                write!(f, "{too_high} was already too high, so this answer is too")
+            }
+            Refusal::NotAbove { too_low } => {
+                write!(f, "{too_low} was already too low, so this answer is too")
+            }
+        }
+    }
+}
+
+/// Every answer that was ever submitted, stored as lines of
+/// `year day part input-hash outcome answer`, in the order they were submitted.
+#[derive(Debug, Default, PartialEq)]
+pub struct GuessLog {
+    guesses: BTreeMap<AnswerKey, Vec<Guess>>,
+}
+
+impl GuessLog {
+    /// Loads the log from a file. A file that doesn't exist yet is just an empty log.
+    pub fn load(path: &Path) -> Result<Self> {
+        records::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        records::save(self, path)
+    }
+
+    pub fn get(&self, key: &AnswerKey) -> &[Guess] {
+        self.guesses.get(key).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn record(&mut self, key: AnswerKey, answer: impl Into<String>, outcome: Outcome) {
+        self.guesses.entry(key).or_default().push(Guess {
+            answer: answer.into(),
+            outcome,
+        });
+    }
+
+    /// Checks an answer against the earlier guesses for the same day, part and input, refusing it
+    /// if the part is already solved, if it's already known to be wrong, or if it's a number that
+    /// isn't below every answer that was too high and above every answer that was too low.
+    pub fn vet(&self, key: &AnswerKey, answer: &str) -> Result<(), Refusal> {
+        let guesses = self.get(key);
+
+        if let Some(solved) = guesses.iter().find(|g| g.outcome == Outcome::Correct) {
+            return Err(Refusal::Solved {
+                answer: solved.answer.clone(),
+            });
+        }
+
+        if let Some(known) = guesses.iter().find(|g| {
+            g.answer == answer
+                && matches!(
+                    g.outcome,
+                    Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow
+                )
+        }) {
+            return Err(Refusal::KnownWrong {
+                outcome: known.outcome,
+            });
+        }
+
+        let Ok(number) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |outcome| {
+            guesses
+                .iter()
+                .filter(move |g| g.outcome == outcome)
+                .filter_map(|g| g.answer.parse::<i128>().ok())
+        };
+
+        if let Some(too_high) = bound(Outcome::TooHigh).min().filter(|&b| number >= b) {
+            return Err(Refusal::NotBelow { too_high });
+        }
+
+        if let Some(too_low) = bound(Outcome::TooLow).max().filter(|&b| number <= b) {
+            return Err(Refusal::NotAbove { too_low });
+        }
+
+        Ok(())
+    }
+}
+
+impl Records for GuessLog {
+    const NAME: &'static str = "guesses";
+    const FIELDS: &'static [&'static str] = &["outcome", "answer"];
+
+    fn insert(&mut self, key: AnswerKey, fields: &[&str]) -> Option<()> {
+        self.record(key, fields[1], Outcome::from_tag(fields[0])?);
+        Some(())
+    }
+
+    fn records(&self) -> Vec<(AnswerKey, Vec<String>)> {
+        self.guesses
+            .iter()
+            .flat_map(|(key, guesses)| {
+                guesses
+                    .iter()
+                    .map(|guess| (*key, vec![guess.outcome.to_string(), guess.answer.clone()]))
+            })
+            .collect()
+    }
+}
+
+impl std::str::FromStr for GuessLog {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        records::parse(s)
+    }
+}
+
+impl std::fmt::Display for GuessLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&records::format(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::input_hash;
+
+    fn key(day: u32, part: u8) -> AnswerKey {
+        AnswerKey {
+            year: 2023,
+            day,
+            part,
+            input: input_hash("some input"),
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut log = GuessLog::default();
+        log.record(key(5, 2), "4252267481", Outcome::TooHigh);
+        log.record(key(5, 2), "12", Outcome::RateLimited);
+        log.record(key(5, 2), "46", Outcome::Correct);
+        log.record(key(10, 1), "some text", Outcome::Wrong);
+
+        let parsed: GuessLog = log.to_string().parse().unwrap();
+        assert_eq!(parsed, log);
+        assert_eq!(parsed.get(&key(5, 2)).len(), 3);
+        assert_eq!(parsed.get(&key(5, 2))[2].outcome, Outcome::Correct);
+    }
+
+    #[test]
+    fn test_vet() {
+        let mut log = GuessLog::default();
+        log.record(key(1, 1), "100", Outcome::TooHigh);
+        log.record(key(1, 1), "80", Outcome::TooHigh);
+        log.record(key(1, 1), "20", Outcome::TooLow);
+        log.record(key(1, 1), "50", Outcome::Wrong);
+        log.record(key(1, 1), "60", Outcome::RateLimited);
+
+        assert_eq!(log.vet(&key(1, 1), "79"), Ok(()));
+        // rate limited guesses were never checked, so they can be submitted again
+        assert_eq!(log.vet(&key(1, 1), "60"), Ok(()));
+        assert_eq!(
+            log.vet(&key(1, 1), "50"),
+            Err(Refusal::KnownWrong {
+                outcome: Outcome::Wrong
+            })
+        );
+        assert_eq!(
+            log.vet(&key(1, 1), "90"),
+            Err(Refusal::NotBelow { too_high: 80 })
+        );
+        assert_eq!(
+            log.vet(&key(1, 1), "20"),
+            Err(Refusal::KnownWrong {
+                outcome: Outcome::TooLow
+            })
+        );
+        assert_eq!(
+            log.vet(&key(1, 1), "-3"),
+            Err(Refusal::NotAbove { too_low: 20 })
+        );
+        assert_eq!(log.vet(&key(1, 2), "90"), Ok(()));
+
+        log.record(key(1, 1), "42", Outcome::Correct);
+        assert_eq!(
+            log.vet(&key(1, 1), "43"),
+            Err(Refusal::Solved {
+                answer: "42".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_bad_line() {
+        let err = "2023 1 1 00000000000000ff maybe 55386".parse::<GuessLog>();
+        assert!(err.unwrap_err().to_string().contains("line 1"));
+    }
+}
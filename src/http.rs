@@ -0,0 +1,286 @@
+//! Just enough of an HTTP/1.1 client, on top of tokio, to talk to the Advent of Code site: one
+//! request per connection, with TLS for `https://` URLs and plain TCP for `http://` ones (which is
+//! what the tests' stub servers speak).
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use color_eyre::eyre::{bail, eyre, WrapErr};
+use color_eyre::Result;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+use tokio_rustls::TlsConnector;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Url {
+    pub tls: bool,
+    pub host: String,
+    pub port: u16,
+    /// Always starts with a `/`.
+    pub path: String,
+}
+
+impl FromStr for Url {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (tls, rest) = match s.split_once("://") {
+            Some(("https", rest)) => (true, rest),
+            Some(("http", rest)) => (false, rest),
+            _ => bail!("{s} is not an http:// or https:// URL"),
+        };
+
+        let (authority, path) = match rest.find('/') {
+            Some(idx) => (&rest[..idx], &rest[idx..]),
+            None => (rest, "/"),
+        };
+
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (
+                host,
+                port.parse()
+                    .map_err(|_| eyre!("{s} does not have a valid port"))?,
+            ),
+            None => (authority, if tls { 443 } else { 80 }),
+        };
+
+        if host.is_empty() {
+            bail!("{s} does not have a host");
+        }
+
+        Ok(Url {
+            tls,
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        })
+    }
+}
+
+impl Url {
+    /// The host, with the port too unless it's the default one for the scheme, as it goes in a
+    /// `Host` header.
+    pub fn authority(&self) -> String {
+        match (self.tls, self.port) {
+            (true, 443) | (false, 80) => self.host.clone(),
+            (_, port) => format!("{}:{port}", self.host),
+        }
+    }
+
+    /// Appends a path to this URL, e.g. `http://localhost:8080/aoc` + `/2023/day/1/input`.
+    pub fn join(&self, path: &str) -> Url {
+        Url {
+            path: format!(
+                "{}/{}",
+                self.path.trim_end_matches('/'),
+                path.trim_start_matches('/')
+            ),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends a single request and reads the whole response.
+pub async fn send(
+    method: &str,
+    url: &Url,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response> {
+    let mut request = format!(
+        "{method} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        url.path,
+        url.authority()
+    );
+
+    for (name, value) in headers {
+        request.push_str(&format!("{name}: {value}\r\n"));
+    }
+
+    if let Some(body) = body {
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or_default());
+
+    let tcp = TcpStream::connect((url.host.as_str(), url.port))
+        .await
+        .wrap_err_with(|| format!("could not connect to {}:{}", url.host, url.port))?;
+
+    let raw = match url.tls {
+        true => {
+            let mut roots = RootCertStore::empty();
+            roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+            let config = ClientConfig::builder()
+                .with_root_certificates(roots)
+                .with_no_client_auth();
+            let server_name = ServerName::try_from(url.host.clone())
+                .wrap_err_with(|| format!("{} is not a valid server name", url.host))?;
+
+            let stream = TlsConnector::from(Arc::new(config))
+                .connect(server_name, tcp)
+                .await
+                .wrap_err_with(|| format!("TLS handshake with {} failed", url.host))?;
+
+            exchange(stream, &request).await?
+        }
+        false => exchange(tcp, &request).await?,
+    };
+
+    parse_response(&raw)
+}
+
+async fn exchange<S: AsyncRead + AsyncWrite + Unpin>(
+    mut stream: S,
+    request: &str,
+) -> Result<Vec<u8>> {
+    stream.write_all(request.as_bytes()).await?;
+    stream.flush().await?;
+
+    let mut raw = Vec::new();
+    // with `Connection: close` the server hangs up once the response is sent; some TLS servers
+    // skip the close_notify when they do, which isn't a problem once we have the response
+    match stream.read_to_end(&mut raw).await {
+        Ok(_) => Ok(raw),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof && !raw.is_empty() => Ok(raw),
+        Err(e) => Err(e).wrap_err("could not read the response"),
+    }
+}
+
+fn parse_response(raw: &[u8]) -> Result<Response> {
+    let header_end = raw
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(|| eyre!("the response ended before its headers did"))?;
+
+    let head =
+        std::str::from_utf8(&raw[..header_end]).wrap_err("the response headers are not UTF-8")?;
+    let body = &raw[header_end + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| eyre!("the response does not start with a status line"))?;
+
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding")
+                && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+
+    let body = match chunked {
+        true => decode_chunked(body)?,
+        false => body.to_vec(),
+    };
+
+    Ok(Response {
+        status,
+        body: String::from_utf8(body).wrap_err("the response body is not UTF-8")?,
+    })
+}
+
+fn decode_chunked(mut body: &[u8]) -> Result<Vec<u8>> {
+    let mut decoded = Vec::new();
+
+    loop {
+        let line_end = body
+            .windows(2)
+            .position(|w| w == b"\r\n")
+            .ok_or_else(|| eyre!("truncated chunk size"))?;
+
+        let size = std::str::from_utf8(&body[..line_end])
+            .ok()
+            .and_then(|line| usize::from_str_radix(line.split(';').next()?.trim(), 16).ok())
+            .ok_or_else(|| eyre!("invalid chunk size"))?;
+
+        body = &body[line_end + 2..];
+
+        if size == 0 {
+            return Ok(decoded);
+        }
+
+        if body.len() < size {
+            bail!("truncated chunk");
+        }
+
+        decoded.extend_from_slice(&body[..size]);
+        body = body[size..].strip_prefix(b"\r\n").unwrap_or(&body[size..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url() {
+        let url: Url = "https://adventofcode.com".parse().unwrap();
+        assert_eq!(
+            url,
+            Url {
+                tls: true,
+                host: "adventofcode.com".to_string(),
+                port: 443,
+                path: "/".to_string()
+            }
+        );
+
+        let url: Url = "http://127.0.0.1:8080/aoc/".parse().unwrap();
+        assert_eq!((url.tls, url.port), (false, 8080));
+        assert_eq!(url.join("/2023/day/1/input").path, "/aoc/2023/day/1/input");
+
+        assert!("ftp://example.com".parse::<Url>().is_err());
+        assert!("http://:80".parse::<Url>().is_err());
+    }
+
+    #[test]
+    fn test_authority() {
+        let authority = |url: &str| url.parse::<Url>().unwrap().authority();
+
+        assert_eq!(authority("https://adventofcode.com"), "adventofcode.com");
+        assert_eq!(
+            authority("https://adventofcode.com:443/"),
+            "adventofcode.com"
+        );
+        assert_eq!(authority("http://127.0.0.1:80"), "127.0.0.1");
+        assert_eq!(authority("http://127.0.0.1:8080/aoc"), "127.0.0.1:8080");
+        assert_eq!(authority("https://localhost:80"), "localhost:80");
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let raw = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n1 2 3";
+        assert_eq!(
+            parse_response(raw).unwrap(),
+            Response {
+                status: 200,
+                body: "1 2 3".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_chunked_response() {
+        let raw = b"HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nNot \r\n5\r\nFound\r\n0\r\n\r\n";
+        assert_eq!(
+            parse_response(raw).unwrap(),
+            Response {
+                status: 404,
+                body: "Not Found".to_string()
+            }
+        );
+    }
+}
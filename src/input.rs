@@ -0,0 +1,93 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, WrapErr};
+use color_eyre::Result;
+
+/// The directory puzzle inputs are read from when no other directory is configured.
+pub const DEFAULT_INPUT_DIR: &str = "input";
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    /// A specific file, regardless of the day.
+    File(PathBuf),
+    /// Standard input, regardless of the day.
+    Stdin,
+    /// `YYYY/day_N.txt` inside the given directory.
+    Dir(PathBuf),
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Dir(PathBuf::from(DEFAULT_INPUT_DIR))
+    }
+}
+
+/// The path of the given day's input inside an input directory, e.g. `input/2023/day_7.txt`.
+pub fn input_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day_{day}.txt"))
+}
+
+impl InputSource {
+    /// Reads the puzzle input for the given day.
+    pub fn read(&self, year: u32, day: u32) -> Result<String> {
+        match self {
+            InputSource::File(path) => read_file(path, year, day),
+            InputSource::Dir(dir) => read_file(&input_path(dir, year, day), year, day),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .wrap_err_with(|| {
+                        format!("could not read the input for {year} day {day} from stdin")
+                    })?;
+
+                Ok(input)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path, year: u32, day: u32) -> Result<String> {
+    if !path.is_file() {
+        return Err(eyre!(
+            "the input for {year} day {day} was not found at {} (pass --input or --input-dir to read it from somewhere else)",
+            path.display()
+        ));
+    }
+
+    std::fs::read_to_string(path).wrap_err_with(|| {
+        format!(
+            "could not read the input for {year} day {day} from {}",
+            path.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            input_path(Path::new("input"), 2023, 7),
+            PathBuf::from("input/2023/day_7.txt")
+        );
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let source = InputSource::Dir(PathBuf::from("does/not/exist"));
+        let err = source.read(2023, 3).unwrap_err();
+
+        assert!(err.to_string().contains("does/not/exist/2023/day_3.txt"));
+    }
+
+    #[test]
+    fn test_read_from_dir() {
+        let input = InputSource::default().read(2023, 6).unwrap();
+        assert!(input.starts_with("Time:"));
+    }
+}
@@ -0,0 +1,457 @@
+use std::cmp::{max, min};
+use std::ops::{Range, RangeInclusive};
+
+/// A half-open interval of integers, `start..end`, which is empty if `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// The closed interval `first..=last`.
+    pub const fn closed(first: i64, last: i64) -> Self {
+        Self::new(first, last + 1)
+    }
+
+    /// The `len` integers from `start` on, which is how puzzles like to describe ranges.
+    pub const fn with_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(self) -> u64 {
+        match self.is_empty() {
+            true => 0,
+            false => self.start.abs_diff(self.end),
+        }
+    }
+
+    /// The last integer in the interval, if it has any.
+    pub fn last(self) -> Option<i64> {
+        (!self.is_empty()).then_some(self.end - 1)
+    }
+
+    pub fn contains(self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The integers in both intervals, or `None` if they have none in common.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let overlap = Self::new(max(self.start, other.start), min(self.end, other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// The parts of the interval below `at` and from `at` on, either of which can be missing.
+    pub fn split_at(self, at: i64) -> (Option<Self>, Option<Self>) {
+        let below = Self::new(self.start, min(self.end, at));
+        let above = Self::new(max(self.start, at), self.end);
+
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    /// The interval moved along by `offset`.
+    pub fn shift(self, offset: i64) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl From<RangeInclusive<i64>> for Interval {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        Self::closed(*range.start(), *range.end())
+    }
+}
+
+/// A set of integers, stored as the fewest intervals that cover them: sorted, non-empty and neither
+/// overlapping nor touching.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every integer in `interval`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|existing| existing.start <= interval.end);
+
+        let merged = match first < last {
+            true => Interval::new(
+                min(interval.start, self.intervals[first].start),
+                max(interval.end, self.intervals[last - 1].end),
+            ),
+            false => interval,
+        };
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> u64 {
+        self.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().and_then(|interval| interval.last())
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&a), Some(&b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intersection.extend(a.intersection(b));
+
+            // whichever ends first can't overlap anything else in the other set
+            match a.end < b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+
+        intersection
+    }
+
+    /// The integers in this set that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Self::new();
+        let mut first = 0;
+
+        for interval in self.iter() {
+            let mut start = interval.start;
+
+            while other
+                .intervals
+                .get(first)
+                .is_some_and(|removed| removed.end <= start)
+            {
+                first += 1;
+            }
+
+            for removed in other.intervals[first..]
+                .iter()
+                .take_while(|removed| removed.start < interval.end)
+            {
+                difference.insert(Interval::new(start, removed.start));
+                start = max(start, removed.end);
+            }
+
+            difference.insert(Interval::new(start, interval.end));
+        }
+
+        difference
+    }
+
+    /// The parts of the set below `at` and from `at` on.
+    pub fn split_at(&self, at: i64) -> (Self, Self) {
+        let (mut below, mut above) = (Self::new(), Self::new());
+
+        for interval in self.iter() {
+            let (low, high) = interval.split_at(at);
+            below.extend(low);
+            above.extend(high);
+        }
+
+        (below, above)
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self::from_iter([interval])
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        set.extend(intervals);
+        set
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, intervals: I) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+/// A piecewise function on integers that adds a different offset to the integers in each of its
+/// pieces, and leaves the integers outside of them as they are. Whole intervals can be mapped at
+/// once, which is what makes it useful when there are far too many integers to map one by one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OffsetMap {
+    /// Sorted and disjoint.
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl OffsetMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps the integers in `source` by adding `offset` to them, except for any that an earlier
+    /// piece already maps.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        let covered: IntervalSet = self.pieces.iter().map(|&(piece, _)| piece).collect();
+
+        for part in IntervalSet::from(source).difference(&covered).iter() {
+            let idx = self
+                .pieces
+                .partition_point(|(piece, _)| piece.start < part.start);
+            self.pieces.insert(idx, (part, offset));
+        }
+    }
+
+    /// The pieces and their offsets, in order.
+    pub fn pieces(&self) -> &[(Interval, i64)] {
+        &self.pieces
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        let idx = self.pieces.partition_point(|(piece, _)| piece.end <= value);
+
+        match self.pieces.get(idx) {
+            Some(&(piece, offset)) if piece.contains(value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Where every integer in `interval` maps to.
+    pub fn map_interval(&self, interval: Interval) -> IntervalSet {
+        let mut mapped = IntervalSet::new();
+        let mut start = interval.start;
+
+        let first = self
+            .pieces
+            .partition_point(|(piece, _)| piece.end <= interval.start);
+
+        for &(piece, offset) in self.pieces[first..]
+            .iter()
+            .take_while(|(piece, _)| piece.start < interval.end)
+        {
+            // the gap before the piece maps to itself
+            mapped.insert(Interval::new(start, piece.start));
+
+            let overlap = Interval::new(max(start, piece.start), min(interval.end, piece.end));
+            mapped.insert(overlap.shift(offset));
+            start = overlap.end;
+        }
+
+        mapped.insert(Interval::new(start, interval.end));
+        mapped
+    }
+
+    /// Where every integer in `set` maps to.
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        set.iter()
+            .flat_map(|interval| self.map_interval(interval).intervals)
+            .collect()
+    }
+
+    /// The map that undoes this one, for maps that never send two integers to the same place.
+    pub fn inverse(&self) -> Self {
+        let mut inverse = Self::new();
+
+        for &(piece, offset) in &self.pieces {
+            inverse.insert(piece.shift(offset), -offset);
+        }
+
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn set(intervals: &[Range<i64>]) -> IntervalSet {
+        intervals.iter().cloned().map(Interval::from).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::closed(3, 7);
+
+        assert_eq!(interval, Interval::with_len(3, 5));
+        assert_eq!(interval, Interval::from(3..8));
+        assert_eq!((interval.len(), interval.last()), (5, Some(7)));
+        assert!(interval.contains(7) && !interval.contains(8));
+
+        assert_eq!(interval.intersection((6..10).into()), Some((6..8).into()));
+        assert_eq!(interval.intersection((8..10).into()), None);
+        assert_eq!(
+            interval.split_at(5),
+            (Some((3..5).into()), Some((5..8).into()))
+        );
+        assert_eq!(interval.split_at(1), (None, Some(interval)));
+        assert_eq!(interval.shift(-3), (0..5).into());
+
+        assert!(Interval::new(4, 4).is_empty());
+        assert_eq!(Interval::new(4, 2).len(), 0);
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let mut merged = set(&[0..2, 5..7]);
+        merged.insert((2..3).into());
+        merged.insert((4..5).into());
+        assert_eq!(merged.intervals(), set(&[0..3, 4..7]).intervals());
+
+        merged.insert((1..6).into());
+        assert_eq!(merged.intervals(), &[(0..7).into()]);
+
+        let a = set(&[0..5, 10..15]);
+        let b = IntervalSet::from(Interval::from(3..12));
+        assert_eq!(a.union(&b), IntervalSet::from(Interval::from(0..15)));
+        assert_eq!(a.intersection(&b), set(&[3..5, 10..12]));
+        assert_eq!(a.difference(&b), set(&[0..3, 12..15]));
+        assert_eq!(b.difference(&a), IntervalSet::from(Interval::from(5..10)));
+        assert_eq!(
+            a.split_at(12),
+            (
+                set(&[0..5, 10..12]),
+                IntervalSet::from(Interval::from(12..15))
+            )
+        );
+
+        assert_eq!(a.len(), 10);
+        assert_eq!((a.min(), a.max()), (Some(0), Some(14)));
+        assert!(a.contains(10) && !a.contains(5));
+    }
+
+    #[test]
+    fn test_offset_map() {
+        // day 5's "seed-to-soil" example map
+        let mut map = OffsetMap::new();
+        map.insert(Interval::with_len(98, 2), 50 - 98);
+        map.insert(Interval::with_len(50, 48), 52 - 50);
+
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(14), 14);
+        assert_eq!(map.get(99), 51);
+
+        assert_eq!(
+            map.map_interval((45..100).into()),
+            set(&[45..50, 50..52, 52..100])
+        );
+        assert_eq!(map.map_set(&set(&[0..1, 98..99])), set(&[0..1, 50..51]));
+        assert_eq!(map.inverse().get(81), 79);
+
+        // earlier pieces win where they overlap
+        map.insert((0..60).into(), 1000);
+        assert_eq!((map.get(49), map.get(50)), (1049, 52));
+    }
+
+    fn brute_force(set: &IntervalSet) -> BTreeSet<i64> {
+        set.iter().flat_map(|i| i.start..i.end).collect()
+    }
+
+    fn small_set() -> impl Strategy<Value = IntervalSet> {
+        prop::collection::vec((-20i64..20, 0i64..10), 0..6).prop_map(|intervals| {
+            intervals
+                .into_iter()
+                .map(|(start, len)| Interval::with_len(start, len))
+                .collect()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_set_operations_match_brute_force(a in small_set(), b in small_set(), at in -25i64..25) {
+            let (a_values, b_values) = (brute_force(&a), brute_force(&b));
+
+            prop_assert_eq!(brute_force(&a.union(&b)), &a_values | &b_values);
+            prop_assert_eq!(brute_force(&a.intersection(&b)), &a_values & &b_values);
+            prop_assert_eq!(brute_force(&a.difference(&b)), &a_values - &b_values);
+
+            let (below, above) = a.split_at(at);
+            prop_assert!(brute_force(&below).iter().all(|&v| v < at));
+            prop_assert!(brute_force(&above).iter().all(|&v| v >= at));
+            prop_assert_eq!(below.len() + above.len(), a.len());
+
+            // and the representation stays canonical
+            for pair in a.union(&b).intervals().windows(2) {
+                prop_assert!(pair[0].end < pair[1].start);
+            }
+        }
+
+        #[test]
+        fn prop_offset_map_maps_intervals_like_values(
+            pieces in prop::collection::vec((-20i64..20, 0i64..10, -30i64..30), 0..5),
+            interval in (-25i64..25, 0i64..20),
+        ) {
+            let mut map = OffsetMap::new();
+            for (start, len, offset) in pieces {
+                map.insert(Interval::with_len(start, len), offset);
+            }
+            let interval = Interval::with_len(interval.0, interval.1);
+
+            let mapped: BTreeSet<i64> = (interval.start..interval.end).map(|v| map.get(v)).collect();
+            prop_assert_eq!(brute_force(&map.map_interval(interval)), mapped);
+        }
+    }
+}
@@ -1,10 +1,20 @@
-pub mod day1;
-pub mod day10;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
+pub mod answers;
+pub mod client;
+pub mod error;
+pub mod fixtures;
+pub mod graph;
+pub mod grid;
+pub mod guesses;
+pub mod http;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod parse;
+pub mod point;
+pub mod records;
+pub mod render;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod y2023;
@@ -0,0 +1,232 @@
+//! Number theory for the puzzles where several cycles have to line up.
+//!
+//! Everything works in `u128`/`i128`, so that products of puzzle-sized (`u64`) numbers can't
+//! overflow, and the functions whose results can still outgrow that return `None` instead.
+
+/// The greatest common divisor, with `gcd(0, 0) == 0`.
+pub fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple, or `None` if it doesn't fit in a `u128`. The lcm of anything and 0
+/// is 0.
+pub fn lcm(a: u128, b: u128) -> Option<u128> {
+    match (a, b) {
+        (0, _) | (_, 0) => Some(0),
+        (a, b) => (a / gcd(a, b)).checked_mul(b),
+    }
+}
+
+/// The least common multiple of all of `nums` (1 if there are none), or `None` if it doesn't fit
+/// in a `u128`.
+pub fn lcm_all(nums: impl IntoIterator<Item = u128>) -> Option<u128> {
+    nums.into_iter().try_fold(1, lcm)
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g`, where `g` is the (non-negative) greatest
+/// common divisor of `a` and `b`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, which only exists when `a` and `m` are coprime.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `a * b mod m` without overflowing, for `a` and `b` in `0..m`.
+fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // double and add, which only ever needs to hold numbers below 2m
+    let (mut a, mut b, mut result) = (a, b, 0i128);
+    while b > 0 {
+        if b & 1 == 1 {
+            result = ((result as u128 + a as u128) % m as u128) as i128;
+        }
+        a = ((a as u128 * 2) % m as u128) as i128;
+        b >>= 1;
+    }
+
+    result
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` with the Chinese Remainder Theorem,
+/// generalized so that the moduli don't have to be coprime. Returns the smallest non-negative `x`
+/// along with the lcm of the moduli (every solution is `x` plus a multiple of it), or `None` if the
+/// congruences contradict each other, a modulus isn't positive or the lcm doesn't fit in an `i128`.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0i128, 1i128), |(x, m), (residue, modulus)| {
+            if modulus <= 0 {
+                return None;
+            }
+
+            // x + m * k ≡ residue (mod modulus), which is solvable for k when gcd(m, modulus)
+            // divides the difference
+            let (g, _, _) = extended_gcd(m, modulus);
+            let difference = residue.rem_euclid(modulus) - x.rem_euclid(modulus);
+            if difference % g != 0 {
+                return None;
+            }
+
+            let reduced = modulus / g;
+            let k = mul_mod(
+                (difference / g).rem_euclid(reduced),
+                mod_inverse(m / g, reduced)?,
+                reduced,
+            );
+
+            let lcm = (m / g).checked_mul(modulus)?;
+            let x = x.checked_add(m.checked_mul(k)?)?.rem_euclid(lcm);
+
+            Some((x, lcm))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(0, 0), 0);
+
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u128::MAX, u128::MAX - 1), None);
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all([]), Some(1));
+
+        // what day 8 used to overflow on in u64
+        let big = u64::MAX as u128;
+        assert_eq!(lcm(big, big - 1), Some(big * (big - 1)));
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(1, 0), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // not coprime, but consistent
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        // not coprime, and contradictory
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(1, 0)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+
+        // moduli big enough that the intermediate products don't fit in an i128
+        let (p, q) = (2305843009213693951, 18446744073709551557);
+        let (x, m) = crt([(5, p), (7, q)]).unwrap();
+        assert_eq!(m, p * q);
+        assert_eq!((x % p, x % q), (5, 7));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_gcd_divides_both(a in 0u128..1 << 64, b in 0u128..1 << 64) {
+            let g = gcd(a, b);
+
+            if g == 0 {
+                prop_assert_eq!((a, b), (0, 0));
+            } else {
+                prop_assert_eq!((a % g, b % g), (0, 0));
+                prop_assert_eq!(gcd(a / g, b / g), 1);
+            }
+        }
+
+        #[test]
+        fn prop_lcm_is_a_common_multiple(a in 1u128..1 << 64, b in 1u128..1 << 64) {
+            let l = lcm(a, b).unwrap();
+
+            prop_assert_eq!((l % a, l % b), (0, 0));
+            prop_assert_eq!(l * gcd(a, b), a * b);
+        }
+
+        #[test]
+        fn prop_extended_gcd(a in -(1i128 << 62)..1 << 62, b in -(1i128 << 62)..1 << 62) {
+            let (g, x, y) = extended_gcd(a, b);
+
+            prop_assert_eq!(a * x + b * y, g);
+            prop_assert_eq!(g as u128, gcd(a.unsigned_abs(), b.unsigned_abs()));
+        }
+
+        #[test]
+        fn prop_mod_inverse(a in any::<i64>(), m in 1i128..1 << 62) {
+            let a = a as i128;
+
+            match mod_inverse(a, m) {
+                Some(inverse) => {
+                    prop_assert!((0..m).contains(&inverse));
+                    prop_assert_eq!(mul_mod(a.rem_euclid(m), inverse, m), 1 % m);
+                }
+                None => prop_assert_ne!(gcd(a.unsigned_abs(), m as u128), 1),
+            }
+        }
+
+        #[test]
+        fn prop_crt_solves_every_congruence(
+            congruences in prop::collection::vec((any::<u32>(), 1i128..30), 1..5),
+        ) {
+            let congruences: Vec<(i128, i128)> = congruences
+                .into_iter()
+                .map(|(residue, modulus)| (residue as i128, modulus))
+                .collect();
+
+            match crt(congruences.iter().copied()) {
+                Some((x, lcm)) => {
+                    prop_assert!((0..lcm).contains(&x));
+                    for &(residue, modulus) in &congruences {
+                        prop_assert_eq!(x % modulus, residue % modulus);
+                    }
+                }
+                // contradictory, which brute force over one full period agrees with
+                None => {
+                    let lcm = lcm_all(congruences.iter().map(|&(_, m)| m as u128)).unwrap() as i128;
+                    let solves_all = |x: i128| {
+                        congruences
+                            .iter()
+                            .all(|&(residue, modulus)| x % modulus == residue % modulus)
+                    };
+                    prop_assert!(!(0..lcm).any(solves_all));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! nom combinators for the shapes puzzle inputs keep coming in, and the glue to run a parser over
+//! a whole input and report whatever it couldn't make sense of as a located `ParseError`.
+
+use std::str::FromStr;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, digit1, line_ending, space0, space1};
+use nom::combinator::{opt, recognize};
+use nom::error::{Error, ErrorKind};
+use nom::multi::{many1_count, separated_list1};
+use nom::sequence::{delimited, pair, preceded, tuple};
+use nom::IResult;
+
+use crate::error::ParseError;
+
+/// The integer types `number` can parse.
+pub trait Integer: FromStr {
+    const SIGNED: bool;
+}
+
+macro_rules! integers {
+    ($signed:literal: $($ty:ty),*) => {
+        $(impl Integer for $ty {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+integers!(false: u8, u16, u32, u64, u128, usize);
+integers!(true: i8, i16, i32, i64, i128, isize);
+
+/// A decimal number of any integer type, with a leading `-` if the type is signed. A number that
+/// doesn't fit in the type is an error rather than being truncated.
+pub fn number<T: Integer>(input: &str) -> IResult<&str, T> {
+    let (rest, digits) = match T::SIGNED {
+        true => recognize(pair(opt(char('-')), digit1))(input)?,
+        false => digit1(input)?,
+    };
+
+    match digits.parse() {
+        Ok(number) => Ok((rest, number)),
+        Err(_) => Err(nom::Err::Error(Error::new(input, ErrorKind::TooLarge))),
+    }
+}
+
+/// Numbers separated by spaces (or tabs) on a single line, e.g. `79 14  55 13`.
+pub fn numbers<T: Integer>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, number)(input)
+}
+
+/// A `label: value` line, e.g. `labelled("Time", numbers)` for `Time:      7  15   30`.
+pub fn labelled<'a, O>(
+    label: &'a str,
+    value: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    preceded(tuple((tag(label), char(':'), space0)), value)
+}
+
+/// A `label id: value` line, e.g. `numbered("Card", numbers)` for `Card  12: 41 48 83`, giving the
+/// id along with the value.
+pub fn numbered<'a, N: Integer, O>(
+    label: &'a str,
+    value: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (N, O)> {
+    pair(
+        delimited(pair(tag(label), space1), number, pair(char(':'), space0)),
+        value,
+    )
+}
+
+/// One or more lines of `line`.
+pub fn lines<'a, O>(
+    line: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(line_ending, line)
+}
+
+/// The end of a line followed by one or more blank lines, which is what separates sections.
+pub fn blank_line(input: &str) -> IResult<&str, ()> {
+    let (input, _) = preceded(line_ending, many1_count(pair(space0, line_ending)))(input)?;
+
+    Ok((input, ()))
+}
+
+/// One or more sections of `section`, separated by blank lines.
+pub fn sections<'a, O>(
+    section: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(blank_line, section)
+}
+
+/// Runs `parser` over the whole of `input` (less any surrounding whitespace). Anything it fails on
+/// or leaves unparsed is reported as an error.
+pub fn parse_all<'a, O>(
+    day: u32,
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, ParseError> {
+    run(day, input, input.trim(), parser)
+}
+
+/// Runs `parser` over every line of `input` (less any surrounding whitespace), like `parse_all`
+/// does over the whole input.
+pub fn parse_lines<'a, O>(
+    day: u32,
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<Vec<O>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| run(day, input, line.trim(), &mut parser))
+        .collect()
+}
+
+/// Runs `parser` over `at`, a sub-slice of `input`, and reports errors against the whole input.
+fn run<'a, O>(
+    day: u32,
+    input: &'a str,
+    at: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, ParseError> {
+    let (rest, output) = parser(at).map_err(|e| ParseError::from_nom(day, input, e))?;
+
+    // point at what's left over rather than at the whitespace before it
+    match rest.trim_start() {
+        "" => Ok(output),
+        rest => Err(ParseError::new(
+            day,
+            input,
+            rest,
+            "unexpected trailing input",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<u8>("255 1"), Ok((" 1", 255)));
+        assert_eq!(number::<i64>("-17,"), Ok((",", -17)));
+        assert_eq!(number::<u128>("0"), Ok(("", 0)));
+        assert!(number::<u8>("256").is_err());
+        assert!(number::<u32>("-1").is_err());
+        assert!(number::<i32>("x").is_err());
+
+        assert_eq!(
+            numbers::<u32>("79 14  55\t13\n"),
+            Ok(("\n", vec![79, 14, 55, 13]))
+        );
+        assert_eq!(numbers::<i32>("1 -2 x"), Ok((" x", vec![1, -2])));
+    }
+
+    #[test]
+    fn test_labelled() {
+        assert_eq!(
+            labelled("Time", numbers::<u64>)("Time:      7  15"),
+            Ok(("", vec![7, 15]))
+        );
+        assert_eq!(
+            numbered::<u32, _>("Card", numbers::<u32>)("Card  12: 41 48 | 1"),
+            Ok((" | 1", (12, vec![41, 48])))
+        );
+        assert!(labelled("Time", numbers::<u64>)("Distance: 9").is_err());
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "1 2\n3 4\n\n5\n \n\n6";
+
+        assert_eq!(
+            sections(lines(numbers::<u32>))(input),
+            Ok((
+                "",
+                vec![vec![vec![1, 2], vec![3, 4]], vec![vec![5]], vec![vec![6]]]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(parse_all(1, "\n1 2\n", numbers::<u32>), Ok(vec![1, 2]));
+
+        let err = parse_all(1, "1 2\n3", numbers::<u32>).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "unexpected trailing input");
+
+        let err = parse_all(1, "99999999999 1", numbers::<u32>).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+        assert_eq!(err.message, "number out of range");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines(1, "1 2\n 3\n", numbers::<u32>),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+
+        let err = parse_lines(1, "1 2\n3 four", numbers::<u32>).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.snippet, "3 four");
+    }
+}
@@ -0,0 +1,211 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position (or an offset between two positions) on a 2D plane, with rows growing downwards and
+/// columns growing to the right, like a puzzle input. Coordinates are signed so that stepping off
+/// the edge of a grid gives a point outside of it rather than an underflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+
+    /// The number of steps between the two points, moving one row or column at a time.
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The point as `(row, column)` indices into a grid, or `None` if either is negative.
+    pub fn to_indices(self) -> Option<(usize, usize)> {
+        Some((
+            usize::try_from(self.row).ok()?,
+            usize::try_from(self.col).ok()?,
+        ))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    /// Converts `(row, column)` grid indices to a point.
+    fn from((row, col): (usize, usize)) -> Self {
+        Self::new(row as i64, col as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self.step(direction)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.row, -self.col)
+    }
+}
+
+/// A compass direction, with north being up a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The four directions that share an edge with a cell, clockwise from north.
+    pub const CARDINAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// Every direction, diagonals included, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// The offset of a single step in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Self::North => Point::new(-1, 0),
+            Self::NorthEast => Point::new(-1, 1),
+            Self::East => Point::new(0, 1),
+            Self::SouthEast => Point::new(1, 1),
+            Self::South => Point::new(1, 0),
+            Self::SouthWest => Point::new(1, -1),
+            Self::West => Point::new(0, -1),
+            Self::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Self::CARDINAL.contains(&self)
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// The direction a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// The direction `eighths` eighths of a turn clockwise.
+    fn rotate(self, eighths: usize) -> Self {
+        let idx = Self::ALL.iter().position(|&d| d == self).unwrap();
+        Self::ALL[(idx + eighths) % Self::ALL.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point::new(2, 3);
+
+        assert_eq!(p + Point::new(1, -5), Point::new(3, -2));
+        assert_eq!(p - Point::new(3, 3), Point::new(-1, 0));
+        assert_eq!(p * 2, Point::new(4, 6));
+        assert_eq!(-p, Point::new(-2, -3));
+        assert_eq!(p + Direction::North, Point::new(1, 3));
+        assert_eq!(p.step(Direction::SouthWest), Point::new(3, 2));
+        assert_eq!(p.manhattan(Point::new(-1, 5)), 5);
+    }
+
+    #[test]
+    fn test_to_indices() {
+        assert_eq!(Point::new(2, 3).to_indices(), Some((2, 3)));
+        assert_eq!(Point::new(0, 0).step(Direction::West).to_indices(), None);
+        assert_eq!(Point::from((4, 5)), Point::new(4, 5));
+    }
+
+    #[test]
+    fn test_turning() {
+        use Direction::*;
+
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(West.turn_right(), North);
+        assert_eq!(NorthEast.turn_right(), SouthEast);
+        assert_eq!(SouthWest.reverse(), NorthEast);
+        assert!(NorthWest.is_diagonal());
+        assert!(!South.is_diagonal());
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! The line format that both recorded answers and submitted guesses are kept in: one record per
+//! line, starting with the `year day part input-hash` of its [`AnswerKey`] and followed by the
+//! record's own fields. Blank lines and `#` comments are skipped.
+
+use std::path::Path;
+
+use color_eyre::eyre::{eyre, WrapErr};
+use color_eyre::Result;
+
+use crate::answers::AnswerKey;
+
+/// Something kept in a file of keyed records.
+pub trait Records: Default {
+    /// What the records are, for errors, e.g. "answers".
+    const NAME: &'static str;
+
+    /// The names of the fields after the key. The last one runs to the end of the line, so that
+    /// text answers can contain spaces.
+    const FIELDS: &'static [&'static str];
+
+    /// Adds a record read from a line, with one value per field, or returns `None` if the values
+    /// don't make sense.
+    fn insert(&mut self, key: AnswerKey, fields: &[&str]) -> Option<()>;
+
+    /// Every record, in the order it should be written, with one value per field.
+    fn records(&self) -> Vec<(AnswerKey, Vec<String>)>;
+}
+
+/// Loads records from a file. A file that doesn't exist yet just has no records in it.
+pub fn load<R: Records>(path: &Path) -> Result<R> {
+    if !path.exists() {
+        return Ok(R::default());
+    }
+
+    let contents = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("could not read {} from {}", R::NAME, path.display()))?;
+
+    parse(&contents).wrap_err_with(|| format!("could not parse {} in {}", R::NAME, path.display()))
+}
+
+pub fn save<R: Records>(records: &R, path: &Path) -> Result<()> {
+    std::fs::write(path, format(records))
+        .wrap_err_with(|| format!("could not write {} to {}", R::NAME, path.display()))
+}
+
+pub fn parse<R: Records>(s: &str) -> Result<R> {
+    let mut records = R::default();
+
+    for (idx, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let bad_line = || eyre!("line {} is not `{}`", idx + 1, header::<R>());
+
+        let fields: Vec<&str> = line
+            .splitn(4 + R::FIELDS.len(), char::is_whitespace)
+            .collect();
+        let [year, day, part, input, rest @ ..] = &fields[..] else {
+            return Err(bad_line());
+        };
+        if rest.len() != R::FIELDS.len() {
+            return Err(bad_line());
+        }
+
+        let key = AnswerKey {
+            year: year.parse().map_err(|_| bad_line())?,
+            day: day.parse().map_err(|_| bad_line())?,
+            part: part.parse().map_err(|_| bad_line())?,
+            input: u64::from_str_radix(input, 16).map_err(|_| bad_line())?,
+        };
+        let rest: Vec<&str> = rest.iter().map(|field| field.trim()).collect();
+
+        records.insert(key, &rest).ok_or_else(bad_line)?;
+    }
+
+    Ok(records)
+}
+
+/// The records as the lines of a file, under a comment naming the fields.
+pub fn format<R: Records>(records: &R) -> String {
+    let mut out = format!("# {}\n", header::<R>());
+
+    for (key, fields) in records.records() {
+        out += &format!(
+            "{} {} {} {:016x} {}\n",
+            key.year,
+            key.day,
+            key.part,
+            key.input,
+            fields.join(" ")
+        );
+    }
+
+    out
+}
+
+fn header<R: Records>() -> String {
+    format!("year day part input-hash {}", R::FIELDS.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::AnswerStore;
+    use crate::guesses::GuessLog;
+
+    #[test]
+    fn test_bad_lines_name_the_format() {
+        let err = parse::<AnswerStore>("2023 1 1 00000000000000ff").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1 is not `year day part input-hash answer`"
+        );
+
+        let err = parse::<GuessLog>("\n2023 1 1 00000000000000ff 55386").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2 is not `year day part input-hash outcome answer`"
+        );
+    }
+
+    #[test]
+    fn test_last_field_runs_to_the_end_of_the_line() {
+        let log: GuessLog = parse("2023 1 1 00000000000000ff wrong two words").unwrap();
+        let formatted = format(&log);
+
+        assert_eq!(
+            formatted,
+            "# year day part input-hash outcome answer\n2023 1 1 00000000000000ff wrong two words\n"
+        );
+        assert_eq!(parse::<GuessLog>(&formatted).unwrap(), log);
+    }
+}
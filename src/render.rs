@@ -0,0 +1,240 @@
+//! Pictures of a grid with paths drawn over it, for seeing what a solution actually did. They can be
+//! written as SVG or as binary PPM, neither of which needs anything beyond the standard library.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use color_eyre::eyre::{eyre, WrapErr};
+use color_eyre::Result;
+
+use crate::grid::Grid;
+use crate::point::Point;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0x00, 0x00, 0x00);
+    pub const DARK_GREY: Self = Self(0x30, 0x30, 0x30);
+    pub const GREY: Self = Self(0x80, 0x80, 0x80);
+    pub const WHITE: Self = Self(0xff, 0xff, 0xff);
+    pub const RED: Self = Self(0xe0, 0x40, 0x40);
+    pub const GREEN: Self = Self(0x40, 0xc0, 0x40);
+    pub const BLUE: Self = Self(0x40, 0x80, 0xe0);
+    pub const YELLOW: Self = Self(0xf0, 0xd0, 0x30);
+
+    /// The colour as `#rrggbb`.
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A grid of coloured cells, with any number of paths through the middle of the cells drawn over
+/// the top, each in its own colour.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    cells: Grid<Rgb>,
+    paths: Vec<(Vec<Point>, Rgb)>,
+}
+
+impl Image {
+    /// The width and height of a cell in pixels.
+    pub const CELL_SIZE: usize = 8;
+
+    /// An image of `grid`, with each cell coloured by `colour`.
+    pub fn new<T>(grid: &Grid<T>, mut colour: impl FnMut(Point, &T) -> Rgb) -> Self {
+        let mut cells = Grid::new(grid.width(), grid.height(), Rgb::BLACK);
+        for (pos, cell) in grid.iter() {
+            cells[pos] = colour(pos, cell);
+        }
+
+        Self {
+            cells,
+            paths: Vec::new(),
+        }
+    }
+
+    /// Draws a line through the middle of each cell of `path` in turn. To draw a loop, end the path
+    /// where it started.
+    pub fn with_path(mut self, path: impl IntoIterator<Item = Point>, colour: Rgb) -> Self {
+        self.paths.push((path.into_iter().collect(), colour));
+        self
+    }
+
+    /// The image as an SVG document, with a unit square per cell, scaled up to `CELL_SIZE`.
+    pub fn to_svg(&self) -> String {
+        let (width, height) = (self.cells.width(), self.cells.height());
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {width} {height}\">\n",
+            width * Self::CELL_SIZE,
+            height * Self::CELL_SIZE,
+        );
+
+        // a rect per run of same coloured cells, rather than one per cell
+        for (row, cells) in self.cells.rows().enumerate() {
+            let mut col = 0;
+            for run in cells.chunk_by(|a, b| a == b) {
+                writeln!(
+                    svg,
+                    "<rect x=\"{col}\" y=\"{row}\" width=\"{}\" height=\"1\" fill=\"{}\" shape-rendering=\"crispEdges\"/>",
+                    run.len(),
+                    run[0].hex()
+                )
+                .unwrap();
+                col += run.len();
+            }
+        }
+
+        for (path, colour) in &self.paths {
+            let points: Vec<String> = path
+                .iter()
+                .map(|pos| format!("{}.5,{}.5", pos.col, pos.row))
+                .collect();
+            writeln!(
+                svg,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"0.25\" stroke-linejoin=\"round\" stroke-linecap=\"round\"/>",
+                points.join(" "),
+                colour.hex()
+            )
+            .unwrap();
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The image as a binary (P6) PPM file, with each cell `CELL_SIZE` pixels square.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let width = self.cells.width() * Self::CELL_SIZE;
+        let height = self.cells.height() * Self::CELL_SIZE;
+        let mut pixels = Grid::new(width, height, Rgb::BLACK);
+
+        for (pos, &colour) in self.cells.iter() {
+            let corner = pos * Self::CELL_SIZE as i64;
+            fill_square(&mut pixels, corner, Self::CELL_SIZE, colour);
+        }
+
+        // a line is a square brush dragged from the middle of each cell to the middle of the next
+        let brush = Self::CELL_SIZE / 4;
+        let offset = (Self::CELL_SIZE - brush) as i64 / 2;
+        let centre = |pos: Point| pos * Self::CELL_SIZE as i64 + Point::new(offset, offset);
+        for (path, colour) in &self.paths {
+            for pair in path.windows(2) {
+                let (from, to) = (centre(pair[0]), centre(pair[1]));
+                let steps = from.row.abs_diff(to.row).max(from.col.abs_diff(to.col)) as i64;
+
+                for step in 0..=steps {
+                    let at = match steps {
+                        0 => from,
+                        _ => Point::new(
+                            from.row + (to.row - from.row) * step / steps,
+                            from.col + (to.col - from.col) * step / steps,
+                        ),
+                    };
+                    fill_square(&mut pixels, at, brush, *colour);
+                }
+            }
+
+            if let [only] = path[..] {
+                fill_square(&mut pixels, centre(only), brush, *colour);
+            }
+        }
+
+        let mut ppm = format!("P6\n{width} {height}\n255\n").into_bytes();
+        for (_, &Rgb(r, g, b)) in pixels.iter() {
+            ppm.extend([r, g, b]);
+        }
+
+        ppm
+    }
+
+    /// Writes the image to `path`, as SVG or PPM depending on its extension.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let contents = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("svg") => self.to_svg().into_bytes(),
+            Some("ppm") => self.to_ppm(),
+            _ => {
+                return Err(eyre!(
+                    "can't render to {}, only to .svg or .ppm files",
+                    path.display()
+                ))
+            }
+        };
+
+        std::fs::write(path, contents)
+            .wrap_err_with(|| format!("could not write image to {}", path.display()))
+    }
+}
+
+/// Colours the `size` by `size` square of pixels with its top left corner at `corner`, skipping any
+/// that are off the edge.
+fn fill_square(pixels: &mut Grid<Rgb>, corner: Point, size: usize, colour: Rgb) {
+    for row in 0..size as i64 {
+        for col in 0..size as i64 {
+            pixels.set(corner + Point::new(row, col), colour);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> Image {
+        let grid = Grid::parse(0, "#..\n##.", |c| Ok(c == '#')).unwrap();
+
+        Image::new(&grid, |_, &wall| match wall {
+            true => Rgb::WHITE,
+            false => Rgb::BLACK,
+        })
+        .with_path(
+            [Point::new(0, 1), Point::new(0, 2), Point::new(1, 2)],
+            Rgb::RED,
+        )
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = image().to_svg();
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"24\" height=\"16\" viewBox=\"0 0 3 2\">\n"));
+        assert!(svg.contains("<rect x=\"1\" y=\"0\" width=\"2\" height=\"1\" fill=\"#000000\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"1\" width=\"2\" height=\"1\" fill=\"#ffffff\""));
+        assert!(svg.contains(
+            "<polyline points=\"1.5,0.5 2.5,0.5 2.5,1.5\" fill=\"none\" stroke=\"#e04040\""
+        ));
+        assert_eq!(svg.matches("<rect").count(), 4);
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = image().to_ppm();
+        let header = b"P6\n24 16\n255\n";
+
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 24 * 16 * 3);
+
+        let pixel = |row: usize, col: usize| {
+            let idx = header.len() + (row * 24 + col) * 3;
+            Rgb(ppm[idx], ppm[idx + 1], ppm[idx + 2])
+        };
+        assert_eq!(pixel(0, 0), Rgb::WHITE);
+        assert_eq!(pixel(0, 8), Rgb::BLACK);
+        // along the path, between the middles of the cells it goes through
+        assert_eq!(pixel(3, 12), Rgb::RED);
+        assert_eq!(pixel(3, 19), Rgb::RED);
+        assert_eq!(pixel(12, 19), Rgb::RED);
+        assert_eq!(pixel(12, 12), Rgb::WHITE);
+    }
+
+    #[test]
+    fn test_save_needs_a_known_extension() {
+        let err = image().save(Path::new("out.png")).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "can't render to out.png, only to .svg or .ppm files"
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::input::InputSource;
+use crate::runner::{Day, Run};
+use crate::solution::Answer;
+
+/// How one day went: its answers and timings, or why it couldn't be run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayReport {
+    pub year: u32,
+    pub day: u32,
+    pub result: Result<Run, String>,
+}
+
+/// The answers and timings of several days, to be printed as a table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub parts: Vec<u8>,
+    pub days: Vec<DayReport>,
+    /// How long running every day took, which is less than the sum of the days' times when they were
+    /// run in parallel.
+    pub wall_time: Duration,
+}
+
+/// Runs the given parts of each day against its input. With `parallel`, the days run at the same
+/// time on rayon's thread pool; each day's times then include whatever slowdown that causes.
+pub fn run_days(days: &[&Day], source: &InputSource, parts: &[u8], parallel: bool) -> Report {
+    let run_day = |day: &&Day| DayReport {
+        year: day.year,
+        day: day.day,
+        result: source
+            .read(day.year, day.day)
+            .map_err(|e| e.to_string())
+            .and_then(|input| day.run(&input, parts).map_err(|e| e.to_string())),
+    };
+
+    let start = Instant::now();
+    let days = match parallel {
+        true => days.par_iter().map(run_day).collect(),
+        false => days.iter().map(run_day).collect(),
+    };
+
+    Report {
+        parts: parts.to_vec(),
+        days,
+        wall_time: start.elapsed(),
+    }
+}
+
+impl Report {
+    /// How many days couldn't be run, or had a part that couldn't be answered.
+    pub fn failures(&self) -> usize {
+        self.days
+            .iter()
+            .filter(|day| match &day.result {
+                Ok(run) => run.parts.iter().any(|part| part.answer.is_err()),
+                Err(_) => true,
+            })
+            .count()
+    }
+
+    /// The report as a plain text table, with the columns padded to line up.
+    pub fn table(&self) -> String {
+        let rows = self.rows();
+
+        let widths: Vec<usize> = (0..rows[0].len())
+            .map(|col| {
+                rows.iter()
+                    .filter(|row| row.len() == rows[0].len())
+                    .map(|row| row[col].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let line = |row: &[String]| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{cell:<width$}"))
+                .collect();
+            cells.join("  ").trim_end().to_string() + "\n"
+        };
+
+        let rule: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+
+        let mut out = line(&rows[0]);
+        out += &line(&rule);
+        for row in &rows[1..rows.len() - 1] {
+            out += &line(row);
+        }
+        out += &line(&rule);
+        out += &line(&rows[rows.len() - 1]);
+        out += &format!("wall time: {:.2?}\n", self.wall_time);
+
+        out
+    }
+
+    /// The report as a GitHub flavoured Markdown table.
+    pub fn markdown(&self) -> String {
+        let rows = self.rows();
+
+        let line = |row: &[String]| {
+            let mut cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+            cells.resize(rows[0].len(), String::new());
+            format!("| {} |\n", cells.join(" | "))
+        };
+
+        let mut out = line(&rows[0]);
+        out += &format!("|{}\n", "---|".repeat(rows[0].len()));
+        for row in &rows[1..] {
+            out += &line(row);
+        }
+
+        out
+    }
+
+    /// The report as a JSON array with an object per part of each day, e.g.
+    ///
+    /// ```json
+    /// { "year": 2023, "day": 1, "part": 2, "answer": "54824", "duration": 0.000136, "parse_duration": 0.000001, "error": null }
+    /// ```
+    ///
+    /// Durations are in seconds. Answers are always strings, since they don't all fit in a double,
+    /// and are `null` for unsolved parts.
+    /// A day that couldn't be run has an `error` for each part, and no answer or durations. A part
+    /// that couldn't be answered has an `error` and durations, but no answer.
+    pub fn json(&self) -> String {
+        let mut records = Vec::new();
+
+        for day in &self.days {
+            match &day.result {
+                Ok(run) => records.extend(run.parts.iter().map(|part| JsonRecord {
+                    year: day.year,
+                    day: day.day,
+                    part: part.part,
+                    answer: match &part.answer {
+                        Ok(Answer::Unsolved) | Err(_) => None,
+                        Ok(answer) => Some(answer.to_string()),
+                    },
+                    duration: Some(part.time.as_secs_f64()),
+                    parse_duration: Some(run.parse_time.as_secs_f64()),
+                    error: part.answer.as_ref().err().map(String::as_str),
+                })),
+                Err(e) => records.extend(self.parts.iter().map(|&part| JsonRecord {
+                    year: day.year,
+                    day: day.day,
+                    part,
+                    answer: None,
+                    duration: None,
+                    parse_duration: None,
+                    error: Some(e),
+                })),
+            }
+        }
+
+        serde_json::to_string_pretty(&records).expect("records are always serializable") + "\n"
+    }
+
+    /// The header, a row per day and a row of totals, as the cells of each column. The rows of days
+    /// that couldn't be run only have the day and the error.
+    fn rows(&self) -> Vec<Vec<String>> {
+        let mut header = vec!["day".to_string()];
+        header.extend(self.parts.iter().map(|part| format!("part {part}")));
+        header.push("parse".to_string());
+        header.extend(self.parts.iter().map(|part| format!("part {part} time")));
+        header.push("total".to_string());
+
+        let mut rows = vec![header.clone()];
+        let mut totals = vec![Duration::ZERO; self.parts.len() + 2];
+
+        for day in &self.days {
+            let mut row = vec![day.day.to_string()];
+
+            match &day.result {
+                Ok(run) => {
+                    let mut times = vec![run.parse_time];
+                    times.extend(run.parts.iter().map(|part| part.time));
+                    times.push(times.iter().sum());
+
+                    for (total, time) in totals.iter_mut().zip(&times) {
+                        *total += *time;
+                    }
+
+                    row.extend(run.parts.iter().map(|part| match &part.answer {
+                        Ok(answer) => answer.to_string(),
+                        Err(e) => format!("error: {e}"),
+                    }));
+                    row.extend(times.iter().map(|time| format!("{time:.2?}")));
+                }
+                // left short, so that the error can run on past the columns it doesn't fit in
+                Err(e) => row.push(format!("error: {e}")),
+            }
+
+            rows.push(row);
+        }
+
+        let mut total_row = vec!["total".to_string()];
+        total_row.resize(self.parts.len() + 1, String::new());
+        total_row.extend(totals.iter().map(|time| format!("{time:.2?}")));
+        rows.push(total_row);
+
+        rows
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: Option<String>,
+    duration: Option<f64>,
+    parse_duration: Option<f64>,
+    error: Option<&'a str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::PartRun;
+
+    fn report() -> Report {
+        let ms = Duration::from_millis;
+
+        Report {
+            parts: vec![1, 2],
+            days: vec![
+                DayReport {
+                    year: 2023,
+                    day: 1,
+                    result: Ok(Run {
+                        parse_time: ms(1),
+                        parts: vec![
+                            PartRun {
+                                part: 1,
+                                answer: Ok(Answer::Number(55386)),
+                                time: ms(2),
+                            },
+                            PartRun {
+                                part: 2,
+                                answer: Ok(Answer::Unsolved),
+                                time: ms(0),
+                            },
+                        ],
+                    }),
+                },
+                DayReport {
+                    year: 2023,
+                    day: 2,
+                    result: Err("no input | here".to_string()),
+                },
+            ],
+            wall_time: ms(5),
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        assert_eq!(
+            report().table(),
+            "\
+day    part 1  part 2    parse   part 1 time  part 2 time  total
+-----  ------  --------  ------  -----------  -----------  ------
+1      55386   unsolved  1.00ms  2.00ms       0.00ns       3.00ms
+2      error: no input | here
+-----  ------  --------  ------  -----------  -----------  ------
+total                    1.00ms  2.00ms       0.00ns       3.00ms
+wall time: 5.00ms
+"
+        );
+    }
+
+    #[test]
+    fn test_markdown() {
+        assert_eq!(
+            report().markdown(),
+            "\
+| day | part 1 | part 2 | parse | part 1 time | part 2 time | total |
+|---|---|---|---|---|---|---|
+| 1 | 55386 | unsolved | 1.00ms | 2.00ms | 0.00ns | 3.00ms |
+| 2 | error: no input \\| here |  |  |  |  |  |
+| total |  |  | 1.00ms | 2.00ms | 0.00ns | 3.00ms |
+"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let json: serde_json::Value = serde_json::from_str(&report().json()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "year": 2023, "day": 1, "part": 1, "answer": "55386", "duration": 0.002, "parse_duration": 0.001, "error": null },
+                { "year": 2023, "day": 1, "part": 2, "answer": null, "duration": 0.0, "parse_duration": 0.001, "error": null },
+                { "year": 2023, "day": 2, "part": 1, "answer": null, "duration": null, "parse_duration": null, "error": "no input | here" },
+                { "year": 2023, "day": 2, "part": 2, "answer": null, "duration": null, "parse_duration": null, "error": "no input | here" },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_part_error() {
+        let mut report = report();
+        report.days.truncate(1);
+        report.parts = vec![2];
+        let run = report.days[0].result.as_mut().unwrap();
+        run.parts.remove(0);
+        run.parts[0].answer = Err("no answer".to_string());
+
+        assert_eq!(report.failures(), 1);
+        assert!(report.table().contains("\n1      error: no answer  1.00ms"));
+
+        let json: serde_json::Value = serde_json::from_str(&report.json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "year": 2023, "day": 1, "part": 2, "answer": null, "duration": 0.0, "parse_duration": 0.001, "error": "no answer" },
+            ])
+        );
+    }
+}
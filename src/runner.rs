@@ -0,0 +1,115 @@
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+use crate::render::Image;
+use crate::solution::{Answer, Solution};
+use crate::y2023;
+
+/// A registered day's solution, with the solution's parsed type erased so that every day can live in
+/// the same registry.
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    run: fn(&str, &[u8]) -> Result<Run, ParseError>,
+    render: fn(&str) -> Result<Option<Image>, ParseError>,
+}
+
+/// The answers a day gave and how long each step took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartRun {
+    pub part: u8,
+    /// The answer, or why the part couldn't be answered.
+    pub answer: Result<Answer, String>,
+    pub time: Duration,
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            run: run::<S>,
+            render: render::<S>,
+        }
+    }
+
+    /// Parses the input once and then solves each of the given parts, returning the answers (or why
+    /// there wasn't one) in the same order.
+    pub fn solve(
+        &self,
+        input: &str,
+        parts: &[u8],
+    ) -> Result<Vec<Result<Answer, String>>, ParseError> {
+        let run = self.run(input, parts)?;
+
+        Ok(run.parts.into_iter().map(|part| part.answer).collect())
+    }
+
+    /// Like [`Day::solve`], but also times parsing and each part.
+    pub fn run(&self, input: &str, parts: &[u8]) -> Result<Run, ParseError> {
+        (self.run)(input, parts)
+    }
+
+    /// Parses the input and renders it, or returns `None` if the day can't be rendered.
+    pub fn render(&self, input: &str) -> Result<Option<Image>, ParseError> {
+        (self.render)(input)
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse_in_year(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part_1(&parsed),
+                2 => S::part_2(&parsed),
+                _ => Ok(Answer::Unsolved),
+            };
+
+            PartRun {
+                part,
+                answer: answer.map_err(|e| format!("{e:#}")),
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Run { parse_time, parts })
+}
+
+fn render<S: Solution>(input: &str) -> Result<Option<Image>, ParseError> {
+    Ok(S::render(&S::parse_in_year(input)?))
+}
+
+/// The registered solutions of every year. Each year lists its own days in its module's `DAYS`.
+pub static YEARS: &[&[Day]] = &[y2023::DAYS];
+
+/// The year that commands act on when no other year is given.
+pub const DEFAULT_YEAR: u32 = 2023;
+
+/// Looks up the registered solution for the given day.
+pub fn get(year: u32, day: u32) -> Option<&'static Day> {
+    days(year).into_iter().find(|d| d.day == day)
+}
+
+/// Every registered solution for the given year, in day order.
+pub fn days(year: u32) -> Vec<&'static Day> {
+    let mut days: Vec<_> = YEARS
+        .iter()
+        .flat_map(|days| days.iter())
+        .filter(|d| d.year == year)
+        .collect();
+    days.sort_by_key(|d| d.day);
+    days
+}
@@ -0,0 +1,324 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{bail, eyre, WrapErr};
+use color_eyre::Result;
+
+use crate::fixtures::{self, DEFAULT_EXAMPLES_DIR};
+use crate::input::{self, DEFAULT_INPUT_DIR};
+
+const EXAMPLE_ANSWERS: &str = "\
+# The answers the example in example.txt should give, e.g.
+# part 1: 142
+# part 2: 281 (pending)
+";
+
+/// Creates everything a new day needs inside the crate at `root`: the `src/yYYYY/dayN.rs` module
+/// (from `templates/day_lib`), its `pub mod` and its entry in the year's `DAYS` registry in
+/// `src/yYYYY/mod.rs`, an empty input and an empty example fixture. The first day of a new year
+/// also creates the year's module and registers it in `src/lib.rs` and `src/runner.rs`. Refuses to
+/// touch anything if the day already exists. Returns the paths that were created or changed.
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    let year_dir = root.join("src").join(format!("y{year}"));
+    let module_path = year_dir.join(format!("day{day}.rs"));
+    let year_path = year_dir.join("mod.rs");
+    let lib_path = root.join("src").join("lib.rs");
+    let runner_path = root.join("src").join("runner.rs");
+
+    if module_path.exists() {
+        bail!(
+            "{year} day {day} already exists at {}",
+            module_path.display()
+        );
+    }
+
+    let template = read(&root.join("templates").join("day_lib"))?;
+    let module = template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string());
+
+    // work out every edit up front, so that nothing is written unless everything can be
+    let mut edits = Vec::new();
+
+    match year_path.exists() {
+        true => {
+            let year_module = read(&year_path)?;
+
+            if year_module.contains(&format!("Day::new::<day{day}::Day{day}>()")) {
+                bail!(
+                    "{year} day {day} is already registered in {}",
+                    year_path.display()
+                );
+            }
+
+            let year_module = register_day(&year_module, day).ok_or_else(|| {
+                eyre!(
+                    "could not find the `pub mod` list or the DAYS registry in {}",
+                    year_path.display()
+                )
+            })?;
+            edits.push((year_path, year_module));
+        }
+        false => {
+            let lib = insert_sorted(&read(&lib_path)?, "pub mod ", &format!("pub mod y{year};"))
+                .ok_or_else(|| {
+                    eyre!(
+                        "could not find the `pub mod` list in {}",
+                        lib_path.display()
+                    )
+                })?;
+            let runner = register_year(&read(&runner_path)?, year).ok_or_else(|| {
+                eyre!(
+                    "could not find the YEARS registry in {}",
+                    runner_path.display()
+                )
+            })?;
+
+            edits.push((year_path, year_module(year, day)));
+            edits.push((lib_path, lib));
+            edits.push((runner_path, runner));
+        }
+    }
+
+    std::fs::create_dir_all(&year_dir)
+        .wrap_err_with(|| format!("could not create {}", year_dir.display()))?;
+    write(&module_path, &module)?;
+
+    let mut changed = vec![module_path];
+    for (path, contents) in edits {
+        write(&path, &contents)?;
+        changed.push(path);
+    }
+
+    let input_path = input::input_path(&root.join(DEFAULT_INPUT_DIR), year, day);
+    if !input_path.exists() {
+        create_parent(&input_path)?;
+        write(&input_path, "")?;
+        changed.push(input_path);
+    }
+
+    let examples_dir = fixtures::day_dir(&root.join(DEFAULT_EXAMPLES_DIR), year, day);
+    if !examples_dir.exists() {
+        std::fs::create_dir_all(&examples_dir)
+            .wrap_err_with(|| format!("could not create {}", examples_dir.display()))?;
+
+        let example = examples_dir.join("example.txt");
+        let answers = examples_dir.join("example.answers");
+        write(&example, "")?;
+        write(&answers, EXAMPLE_ANSWERS)?;
+        changed.extend([example, answers]);
+    }
+
+    Ok(changed)
+}
+
+/// The module of a year that doesn't exist yet, with its first day.
+fn year_module(year: u32, day: u32) -> String {
+    format!(
+        "\
+//! The puzzles from Advent of Code {year}.
+
+pub mod day{day};
+
+use crate::runner::Day;
+
+/// Every solved day of {year}, registered for the runner. Kept one day per line, which is what
+/// `aoc new` expects when it adds a day.
+#[rustfmt::skip]
+pub static DAYS: &[Day] = &[
+    Day::new::<day{day}::Day{day}>(),
+];
+"
+    )
+}
+
+/// Adds the day's `pub mod` and its `Day::new` to its year's module.
+fn register_day(year_module: &str, day: u32) -> Option<String> {
+    let year_module = insert_sorted(year_module, "pub mod ", &format!("pub mod day{day};"))?;
+
+    let start = year_module.find("pub static DAYS: &[Day] = &[")?;
+    let end = start + year_module[start..].find("\n];")?;
+
+    Some(format!(
+        "{}\n    Day::new::<day{day}::Day{day}>(),{}",
+        &year_module[..end],
+        &year_module[end..]
+    ))
+}
+
+/// Adds the year's `use` and its `DAYS` to the runner's registry.
+fn register_year(runner: &str, year: u32) -> Option<String> {
+    let runner = insert_sorted(runner, "use crate::", &format!("use crate::y{year};"))?;
+
+    let start = runner.find("pub static YEARS: &[&[Day]] = &[")?;
+    let end = start + runner[start..].find("];")?;
+
+    Some(format!(
+        "{}, y{year}::DAYS{}",
+        &runner[..end].trim_end_matches([',', ' ', '\n']),
+        &runner[end..]
+    ))
+}
+
+/// Inserts `line` into the contiguous block of lines starting with `prefix`, keeping the block in
+/// the order rustfmt keeps it in: path segment by path segment, so `day1` < `day10` < `day2`.
+fn insert_sorted(contents: &str, prefix: &str, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let first = lines.iter().position(|l| l.starts_with(prefix))?;
+    let len = lines[first..]
+        .iter()
+        .take_while(|l| l.starts_with(prefix))
+        .count();
+
+    let sort_key = |line: &str| -> Vec<String> {
+        line.trim_end_matches(';')
+            .split("::")
+            .map(str::to_string)
+            .collect()
+    };
+
+    let offset = lines[first..first + len]
+        .iter()
+        .position(|l| sort_key(l) > sort_key(line))
+        .unwrap_or(len);
+    lines.insert(first + offset, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+fn create_parent(path: &Path) -> Result<()> {
+    match path.parent() {
+        Some(parent) => std::fs::create_dir_all(parent)
+            .wrap_err_with(|| format!("could not create {}", parent.display())),
+        None => Ok(()),
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).wrap_err_with(|| format!("could not read {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    std::fs::write(path, contents).wrap_err_with(|| format!("could not write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod runner;\npub mod solution;\npub mod y2023;\n";
+    const RUNNER: &str = "\
+use crate::error::ParseError;
+use crate::solution::Solution;
+use crate::y2023;
+
+pub static YEARS: &[&[Day]] = &[y2023::DAYS];
+";
+    const YEAR_2023: &str = "\
+//! The puzzles from Advent of Code 2023.
+
+pub mod day1;
+pub mod day10;
+pub mod day2;
+
+use crate::runner::Day;
+
+pub static DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day10::Day10>(),
+];
+";
+
+    /// A throwaway crate layout with just the files scaffolding reads.
+    fn fake_crate() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(root.join("src/y2023")).unwrap();
+        std::fs::create_dir_all(root.join("templates")).unwrap();
+
+        std::fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        std::fs::write(root.join("src/runner.rs"), RUNNER).unwrap();
+        std::fs::write(root.join("src/y2023/mod.rs"), YEAR_2023).unwrap();
+        std::fs::write(
+            root.join("templates/day_lib"),
+            "pub struct Day{{day}};\nconst YEAR: u32 = {{year}};\n",
+        )
+        .unwrap();
+
+        root
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        assert_eq!(
+            insert_sorted(YEAR_2023, "pub mod ", "pub mod day11;").unwrap(),
+            YEAR_2023.replace("pub mod day10;\n", "pub mod day10;\npub mod day11;\n")
+        );
+        assert_eq!(insert_sorted(LIB, "use ", "use foo;"), None);
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = fake_crate();
+
+        new_day(&root, 2023, 11).unwrap();
+
+        let module = std::fs::read_to_string(root.join("src/y2023/day11.rs")).unwrap();
+        assert_eq!(module, "pub struct Day11;\nconst YEAR: u32 = 2023;\n");
+
+        let year = std::fs::read_to_string(root.join("src/y2023/mod.rs")).unwrap();
+        assert!(year.contains("pub mod day10;\npub mod day11;\npub mod day2;"));
+        assert!(
+            year.contains("    Day::new::<day10::Day10>(),\n    Day::new::<day11::Day11>(),\n];")
+        );
+
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            LIB
+        );
+        assert!(root.join("input/2023/day_11.txt").exists());
+        assert!(root.join("examples/2023/day_11/example.txt").exists());
+        assert!(root.join("examples/2023/day_11/example.answers").exists());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_new_year() {
+        let root = fake_crate();
+
+        new_day(&root, 2024, 1).unwrap();
+
+        let year = std::fs::read_to_string(root.join("src/y2024/mod.rs")).unwrap();
+        assert_eq!(year, year_module(2024, 1));
+
+        let lib = std::fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert_eq!(lib, format!("{LIB}pub mod y2024;\n"));
+
+        let runner = std::fs::read_to_string(root.join("src/runner.rs")).unwrap();
+        assert!(runner.contains("use crate::y2023;\nuse crate::y2024;\n"));
+        assert!(runner.contains("pub static YEARS: &[&[Day]] = &[y2023::DAYS, y2024::DAYS];"));
+
+        assert!(root.join("input/2024/day_1.txt").exists());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_overwrite() {
+        let root = fake_crate();
+        std::fs::write(root.join("src/y2023/day2.rs"), "// solved already").unwrap();
+
+        let err = new_day(&root, 2023, 2).unwrap_err();
+        assert!(err.to_string().contains("2023 day 2 already exists"));
+
+        let module = std::fs::read_to_string(root.join("src/y2023/day2.rs")).unwrap();
+        assert_eq!(module, "// solved already");
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/y2023/mod.rs")).unwrap(),
+            YEAR_2023
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+
+use color_eyre::Result;
+
+use crate::error::ParseError;
+use crate::render::Image;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The part hasn't been solved yet.
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// A day's puzzle: the input is parsed once, and the parsed form is then handed to each part. A part
+/// can still fail on input that parsed, e.g. when the input turns out not to have an answer.
+pub trait Solution {
+    /// The year of the event the puzzle is from.
+    const YEAR: u32;
+
+    /// The day of the puzzle, used when reporting errors.
+    const DAY: u32;
+
+    /// The parsed puzzle input. It may borrow from the raw input.
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError>;
+
+    fn part_1(parsed: &Self::Parsed<'_>) -> Result<Answer>;
+
+    fn part_2(_parsed: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    /// A picture of the parsed input and what the solution made of it, for days that have one.
+    fn render(_parsed: &Self::Parsed<'_>) -> Option<Image> {
+        None
+    }
+
+    /// Parses the input and solves part 1 of it.
+    fn solve_part_1(input: &str) -> Result<Answer> {
+        Self::part_1(&Self::parse_in_year(input)?)
+    }
+
+    /// Parses the input and solves part 2 of it.
+    fn solve_part_2(input: &str) -> Result<Answer> {
+        Self::part_2(&Self::parse_in_year(input)?)
+    }
+
+    /// Parses the input, with any error saying which year's puzzle it was for.
+    fn parse_in_year(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Self::parse(input).map_err(|e| e.in_year(Self::YEAR))
+    }
+}
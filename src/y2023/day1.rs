@@ -0,0 +1,754 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use color_eyre::Result;
+use rayon::prelude::*;
+
+use crate::error::ParseError;
+use crate::solution::{Answer, Solution};
+
+// We once again skip Rust's (normally useful) string complexity by considering every input to be a
+// just a slice of u8's.
+fn bytewise_compare(first: &[u8], last: &[u8]) -> bool {
+    if first.len() != last.len() {
+        return false;
+    }
+
+    for (idx, c) in first.iter().enumerate() {
+        if last[idx] != *c {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// The words (and digits) that count as digits when looking for the first and last digit of a
+/// line. A word's value can be more than one digit, e.g. "ten", in which case it contributes all of
+/// its digits to the calibration value.
+#[derive(Debug, Clone, Default)]
+pub struct Lexicon {
+    /// Longest first, so that the longest word is the one that's found where several match.
+    words: Vec<(Vec<u8>, u32)>,
+    digits: bool,
+    case_insensitive: bool,
+}
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+impl Lexicon {
+    /// A lexicon that recognizes nothing at all, to add words to.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Just the digits `0` to `9`, which is what part 1 looks for.
+    pub fn digits() -> Self {
+        Self::new().with_digits()
+    }
+
+    /// The digits along with the English words for them, "one" to "nine", which is what part 2
+    /// looks for.
+    pub fn english() -> Self {
+        Self::digits().with_words(ENGLISH)
+    }
+
+    /// Also recognizes the digits `0` to `9`.
+    pub fn with_digits(mut self) -> Self {
+        self.digits = true;
+        self
+    }
+
+    /// Also recognizes `word` as standing for `value`.
+    pub fn with_word(self, word: &str, value: u32) -> Self {
+        self.with_words([(word, value)])
+    }
+
+    /// Also recognizes each of the words as standing for its value.
+    pub fn with_words<'a>(mut self, words: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        self.words.extend(
+            words
+                .into_iter()
+                .filter(|(word, _)| !word.is_empty())
+                .map(|(word, value)| (word.as_bytes().to_vec(), value)),
+        );
+        self.words
+            .sort_by_key(|(word, _)| std::cmp::Reverse(word.len()));
+        self
+    }
+
+    /// Matches words regardless of the case of their ASCII letters, so "One" and "ONE" count too.
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    fn is_match(&self, word: &[u8], candidate: &[u8]) -> bool {
+        match self.case_insensitive {
+            true => word.eq_ignore_ascii_case(candidate),
+            false => bytewise_compare(word, candidate),
+        }
+    }
+
+    /// The value of the digit or word that `input` starts with, if any.
+    fn find_prefix(&self, input: &[u8]) -> Option<u32> {
+        match input.first() {
+            Some(c) if self.digits && c.is_ascii_digit() => return Some((c - b'0') as u32),
+            _ => {}
+        }
+
+        self.words
+            .iter()
+            .find(|(word, _)| {
+                input.len() >= word.len() && self.is_match(word, &input[..word.len()])
+            })
+            .map(|&(_, value)| value)
+    }
+
+    /// The value of the digit or word that `input` ends with, if any.
+    fn find_suffix(&self, input: &[u8]) -> Option<u32> {
+        match input.last() {
+            Some(c) if self.digits && c.is_ascii_digit() => return Some((c - b'0') as u32),
+            _ => {}
+        }
+
+        self.words
+            .iter()
+            .find(|(word, _)| {
+                input.len() >= word.len() && self.is_match(word, &input[input.len() - word.len()..])
+            })
+            .map(|&(_, value)| value)
+    }
+}
+
+/// Two-pointer approach for finding the first and last digits (as recognized by `lexicon`) in a
+/// string.
+pub fn find_first_last(input: &str, lexicon: &Lexicon) -> Option<(u32, u32)> {
+    if input.is_empty() {
+        return None;
+    }
+    // we can skip the whole UTF-8 codepoints thing and just treat the input as an array of bytes,
+    // which allows our two-pointer approach to be fairly fast. Words can only ever match on
+    // character boundaries anyway, since no character's encoding appears in the middle of another's.
+    let bytes = input.as_bytes();
+
+    let mut start_ptr = 0;
+    let mut end_ptr = bytes.len() - 1;
+    let mut first_digit: Option<u32> = None;
+    let mut last_digit: Option<u32> = None;
+
+    // Iterate forwards until we find the first digit (by some definition)
+    while first_digit.is_none() && start_ptr < bytes.len() {
+        first_digit = lexicon.find_prefix(&bytes[start_ptr..]);
+        start_ptr += 1;
+    }
+
+    // Iterate backwards until we find the last digit (by some definition)
+    while last_digit.is_none() {
+        last_digit = lexicon.find_suffix(&bytes[..end_ptr + 1]);
+
+        // We can't check end_ptr >= 0 in the loop condition, because it's a usize, so by definition
+        // it could never be less than 0, and we want to avoid wrapping.
+        if end_ptr == 0 {
+            break;
+        }
+
+        end_ptr -= 1;
+    }
+
+    match (first_digit, last_digit) {
+        (Some(first), Some(last)) => Some((first, last)),
+        _ => None,
+    }
+}
+
+/// An Aho-Corasick automaton over a lexicon's words (and digits), which finds where any of them
+/// match in a single pass over the input, however many words there are.
+#[derive(Debug, Clone)]
+struct Automaton {
+    /// The state to go to from each state on each (case folded) byte. State 0 is the root.
+    transitions: Vec<[u32; 256]>,
+    /// The length and value of the word each state spells out, if it's a whole word.
+    words: Vec<Option<(usize, u32)>>,
+    /// Each state's failure state, the state for the longest proper suffix of its text that's also
+    /// in the trie.
+    failures: Vec<usize>,
+    /// The closest state out of each state and its chain of failure states that's a whole word,
+    /// which is the longest word that ends there. The rest follow on from its failure state.
+    output_links: Vec<Option<usize>>,
+    longest: usize,
+    case_insensitive: bool,
+}
+
+impl Automaton {
+    fn new(words: &[(Vec<u8>, u32)], case_insensitive: bool) -> Self {
+        let fold = |c: u8| match case_insensitive {
+            true => c.to_ascii_lowercase(),
+            false => c,
+        };
+
+        // a trie of the words, with 0 meaning there's no edge yet (nothing leads back to the root)
+        let mut transitions = vec![[0u32; 256]];
+        let mut terminals: Vec<Option<(usize, u32)>> = vec![None];
+        for (word, value) in words {
+            let mut state = 0;
+            for &c in word {
+                state = match transitions[state][fold(c) as usize] {
+                    0 => {
+                        transitions.push([0; 256]);
+                        terminals.push(None);
+                        let next = transitions.len() - 1;
+                        transitions[state][fold(c) as usize] = next as u32;
+                        next
+                    }
+                    next => next as usize,
+                };
+            }
+
+            // where a word is in the lexicon twice, the first one wins
+            terminals[state].get_or_insert((word.len(), *value));
+        }
+
+        // turn the trie into a full DFA, breadth first so that every state's failure state is done
+        // before it's needed. A missing edge goes wherever the failure state's edge goes.
+        let mut failures = vec![0usize; transitions.len()];
+        let mut output_links = vec![None; transitions.len()];
+        let mut queue: VecDeque<usize> = transitions[0]
+            .iter()
+            .filter(|&&next| next != 0)
+            .map(|&next| next as usize)
+            .collect();
+
+        while let Some(state) = queue.pop_front() {
+            let failure = failures[state];
+            output_links[state] = match terminals[state] {
+                Some(_) => Some(state),
+                None => output_links[failure],
+            };
+
+            let failure_transitions = transitions[failure];
+            for (next, &fallback) in transitions[state].iter_mut().zip(&failure_transitions) {
+                match *next as usize {
+                    0 => *next = fallback,
+                    next => {
+                        failures[next] = fallback as usize;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        Self {
+            transitions,
+            words: terminals,
+            failures,
+            output_links,
+            longest: words.iter().map(|(word, _)| word.len()).max().unwrap_or(0),
+            case_insensitive,
+        }
+    }
+
+    /// The value of the word that starts first in `bytes` (the longest one, if several start in the
+    /// same place).
+    fn leftmost(&self, bytes: impl Iterator<Item = u8>) -> Option<u32> {
+        let mut state = 0;
+        // the start, length and value of the best match so far
+        let mut best: Option<(usize, usize, u32)> = None;
+
+        for (idx, c) in bytes.enumerate() {
+            // nothing that ends from here on can start before the best match
+            if best.is_some_and(|(start, _, _)| idx >= start + self.longest) {
+                break;
+            }
+
+            state = self.step(state, c);
+
+            if let Some((len, value)) = self.longest_word(state) {
+                let start = idx + 1 - len;
+                if best.is_none_or(|(best_start, best_len, _)| {
+                    start < best_start || (start == best_start && len > best_len)
+                }) {
+                    best = Some((start, len, value));
+                }
+            }
+        }
+
+        best.map(|(_, _, value)| value)
+    }
+
+    fn step(&self, state: usize, c: u8) -> usize {
+        let c = match self.case_insensitive {
+            true => c.to_ascii_lowercase(),
+            false => c,
+        };
+
+        self.transitions[state][c as usize] as usize
+    }
+
+    /// The length and value of the longest word that ends at `state`.
+    fn longest_word(&self, state: usize) -> Option<(usize, u32)> {
+        self.words[self.output_links[state]?]
+    }
+}
+
+/// A digit, or a word for one, found in some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DigitMatch<'a> {
+    /// The byte offset in the text where the match starts.
+    pub start: usize,
+    /// What matched, as it appears in the text.
+    pub text: &'a str,
+    pub value: u32,
+}
+
+impl DigitMatch<'_> {
+    /// The byte offset in the text just after the match.
+    pub fn end(&self) -> usize {
+        self.start + self.text.len()
+    }
+}
+
+/// Every digit (or word for one) in some text, made by `Matcher::find_all`.
+#[derive(Debug, Clone)]
+pub struct Matches<'m, 'a> {
+    automaton: &'m Automaton,
+    input: &'a str,
+    /// How much of the input the automaton has read.
+    end: usize,
+    state: usize,
+    /// The next state with a word that ends at `end` that hasn't been yielded yet.
+    output: Option<usize>,
+}
+
+impl<'a> Iterator for Matches<'_, 'a> {
+    type Item = DigitMatch<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(output) = self.output {
+                let automaton = self.automaton;
+                let (len, value) = automaton.words[output].expect("Expected a whole word");
+                self.output = automaton.output_links[automaton.failures[output]];
+
+                // words can only match whole characters, so this is always on a char boundary
+                let start = self.end - len;
+                return Some(DigitMatch {
+                    start,
+                    text: &self.input[start..self.end],
+                    value,
+                });
+            }
+
+            let c = *self.input.as_bytes().get(self.end)?;
+            self.state = self.automaton.step(self.state, c);
+            self.end += 1;
+            self.output = self.automaton.output_links[self.state];
+        }
+    }
+}
+
+/// Finds the first and last digits (as recognized by a lexicon) with a pair of automata, one over
+/// the words and one over the words reversed, so each is found in a single pass from its own end
+/// of the input. Overlapping words are fine, e.g. "eightwo" gives 8 and 2.
+#[derive(Debug, Clone)]
+pub struct Matcher {
+    forwards: Automaton,
+    backwards: Automaton,
+}
+
+impl Matcher {
+    pub fn new(lexicon: &Lexicon) -> Self {
+        let mut words = lexicon.words.clone();
+        if lexicon.digits {
+            words.extend((0..10).map(|digit| (vec![b'0' + digit as u8], digit)));
+        }
+        // the lexicon's longest first order, so that its first word wins out of any duplicates
+        words.sort_by_key(|(word, _)| std::cmp::Reverse(word.len()));
+
+        let reversed: Vec<(Vec<u8>, u32)> = words
+            .iter()
+            .map(|(word, value)| (word.iter().rev().copied().collect(), *value))
+            .collect();
+
+        Self {
+            forwards: Automaton::new(&words, lexicon.case_insensitive),
+            backwards: Automaton::new(&reversed, lexicon.case_insensitive),
+        }
+    }
+
+    /// The same as `find_first_last`, with the lexicon this matcher was made from.
+    pub fn find_first_last(&self, input: &str) -> Option<(u32, u32)> {
+        let bytes = input.as_bytes();
+
+        let first = self.forwards.leftmost(bytes.iter().copied())?;
+        let last = self.backwards.leftmost(bytes.iter().rev().copied())?;
+
+        Some((first, last))
+    }
+
+    /// Every digit (or word for one) in `input`, overlapping ones included, in the order that they
+    /// end in, and longest first where several end in the same place. Unlike the first and last
+    /// digits, these are found in a single pass over the input.
+    pub fn find_all<'a>(&self, input: &'a str) -> Matches<'_, 'a> {
+        Matches {
+            automaton: &self.forwards,
+            input,
+            end: 0,
+            state: 0,
+            output: None,
+        }
+    }
+}
+
+/// The calibration value made by writing `last` after `first`, e.g. 1 and 2 give 12, and 10 and 2
+/// give 102.
+pub fn calibration_value(first: u32, last: u32) -> u32 {
+    let mut shift = 10;
+    while shift <= last {
+        shift *= 10;
+    }
+
+    first * shift + last
+}
+
+/// What `get_puzzle_result` does about lines without any digits in them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingDigits {
+    /// Fails, listing every line without a digit.
+    #[default]
+    Fail,
+    /// Leaves them out of the sum.
+    Skip,
+}
+
+/// The lines of the input that didn't have any digits in them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingDigitsError {
+    /// 1-based line numbers, in order.
+    pub lines: Vec<usize>,
+}
+
+impl fmt::Display for MissingDigitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self.lines.iter().map(|line| line.to_string()).collect();
+
+        match &lines[..] {
+            [line] => write!(f, "line {line} did not contain a digit"),
+            lines => write!(f, "lines {} did not contain a digit", lines.join(", ")),
+        }
+    }
+}
+
+impl std::error::Error for MissingDigitsError {}
+
+/// Sums the calibration values (the first and last digit of each line, written one after the
+/// other) of every line in the input. Lines without any digits either fail the whole sum or are
+/// skipped, depending on `missing`.
+pub fn get_puzzle_result(
+    input: &str,
+    lexicon: &Lexicon,
+    missing: MissingDigits,
+) -> Result<u32, MissingDigitsError> {
+    let matcher = Matcher::new(lexicon);
+
+    let lines: Vec<&str> = input.lines().collect();
+    let values: Vec<Option<u32>> = lines
+        .par_iter()
+        .map(|line| {
+            let (first, last) = matcher.find_first_last(line)?;
+            Some(calibration_value(first, last))
+        })
+        .collect();
+
+    let without_digits: Vec<usize> = values
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| value.is_none())
+        .map(|(idx, _)| idx + 1)
+        .collect();
+
+    match missing {
+        MissingDigits::Fail if !without_digits.is_empty() => Err(MissingDigitsError {
+            lines: without_digits,
+        }),
+        _ => Ok(values.into_iter().flatten().sum()),
+    }
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 1;
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        // only the end is trimmed, so that lines are numbered the same as in the file
+        Ok(input.trim_end())
+    }
+
+    fn part_1(input: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(get_puzzle_result(input, &Lexicon::digits(), MissingDigits::Fail)?.into())
+    }
+
+    fn part_2(input: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(get_puzzle_result(input, &Lexicon::english(), MissingDigits::Fail)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_find_first_last_when_both_defined() {
+        let input_str = "abc1de3f2zxcv";
+        let result = find_first_last(input_str, &Lexicon::english());
+
+        assert_eq!(result.unwrap(), (1, 2));
+    }
+    #[test]
+    fn test_find_first_last_when_only_first() {
+        let input_str = "1asbddfwerqe";
+        let result = find_first_last(input_str, &Lexicon::english());
+
+        assert_eq!(result.unwrap(), (1, 1));
+    }
+
+    #[test]
+    fn test_find_first_last_when_none() {
+        let input_str = "asbddfwerqe";
+        let result = find_first_last(input_str, &Lexicon::english());
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_find_first_last_when_first_is_string() {
+        let input_str = "one2";
+        let result = find_first_last(input_str, &Lexicon::english());
+
+        assert_eq!(result.unwrap(), (1, 2));
+    }
+
+    #[test]
+    fn test_find_first_last_when_last_is_string() {
+        let input_str = "abone1eight";
+        let result = find_first_last(input_str, &Lexicon::english());
+
+        assert_eq!(result.unwrap(), (1, 8));
+    }
+
+    #[test]
+    fn test_not_recognize_strs() {
+        let input_str = "abone2eight";
+        let result = find_first_last(input_str, &Lexicon::digits());
+
+        assert_eq!(result.unwrap(), (2, 2));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(find_first_last("", &Lexicon::english()), None);
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(find_first_last("a0b7c", &Lexicon::digits()), Some((0, 7)));
+        assert_eq!(find_first_last("zero5", &Lexicon::english()), Some((5, 5)));
+
+        let lexicon = Lexicon::english().with_word("zero", 0);
+        assert_eq!(find_first_last("zero5", &lexicon), Some((0, 5)));
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_eq!(
+            find_first_last("ONE2Three", &Lexicon::english()),
+            Some((2, 2))
+        );
+        assert_eq!(
+            find_first_last("ONE2Three", &Lexicon::english().case_insensitive()),
+            Some((1, 3))
+        );
+    }
+
+    #[test]
+    fn test_other_languages() {
+        let german =
+            Lexicon::new().with_words([("eins", 1), ("zwei", 2), ("drei", 3), ("fünf", 5)]);
+
+        assert_eq!(find_first_last("xeinsfünf", &german), Some((1, 5)));
+        // the digits only count if they're asked for
+        assert_eq!(find_first_last("7drei", &german), Some((3, 3)));
+        assert_eq!(
+            find_first_last("7drei", &german.with_digits()),
+            Some((7, 3))
+        );
+    }
+
+    #[test]
+    fn test_multi_digit_words() {
+        let lexicon = Lexicon::english().with_words([("ten", 10), ("seventeen", 17)]);
+
+        assert_eq!(find_first_last("ten3", &lexicon), Some((10, 3)));
+        // the longest word wins where several start (or end) in the same place
+        assert_eq!(find_first_last("seventeenx", &lexicon), Some((17, 17)));
+        assert_eq!(calibration_value(10, 3), 103);
+        assert_eq!(calibration_value(4, 17), 417);
+        assert_eq!(calibration_value(1, 0), 10);
+        assert_eq!(
+            get_puzzle_result("ten3\n4seventeen", &lexicon, MissingDigits::Fail),
+            Ok(103 + 417)
+        );
+    }
+
+    #[test]
+    fn test_matcher() {
+        let matcher = Matcher::new(&Lexicon::english());
+
+        assert_eq!(matcher.find_first_last("eightwo"), Some((8, 2)));
+        assert_eq!(matcher.find_first_last("xtwone3fouroneight"), Some((2, 8)));
+        assert_eq!(matcher.find_first_last("abone1eight"), Some((1, 8)));
+        assert_eq!(matcher.find_first_last("7"), Some((7, 7)));
+        assert_eq!(matcher.find_first_last("asbddfwerqe"), None);
+        assert_eq!(matcher.find_first_last(""), None);
+
+        // a shorter word that ends first doesn't beat a longer one that starts first
+        let lexicon = Lexicon::new().with_words([("xab", 1), ("a", 2), ("bc", 3)]);
+        assert_eq!(Matcher::new(&lexicon).find_first_last("xabc"), Some((1, 3)));
+
+        let lexicon = Lexicon::english()
+            .with_words([("ten", 10), ("seventeen", 17)])
+            .case_insensitive();
+        assert_eq!(
+            Matcher::new(&lexicon).find_first_last("SevenTeen2TEN"),
+            Some((17, 10))
+        );
+    }
+
+    #[test]
+    fn test_find_all() {
+        let found: Vec<DigitMatch> = Matcher::new(&Lexicon::english())
+            .find_all("xEightwone3")
+            .collect();
+        let summary: Vec<(usize, &str, u32)> =
+            found.iter().map(|m| (m.start, m.text, m.value)).collect();
+        assert_eq!(summary, vec![(5, "two", 2), (7, "one", 1), (10, "3", 3)]);
+        assert_eq!(found[2].end(), 11);
+
+        // the matches only borrow the text, so they can outlive the matcher
+        let line = String::from("fünfEINS7");
+        let found: Vec<DigitMatch> = {
+            let lexicon = Lexicon::digits()
+                .with_words([("fünf", 5), ("eins", 1), ("einsfünf", 15)])
+                .case_insensitive();
+            Matcher::new(&lexicon).find_all(&line).collect()
+        };
+        let found: Vec<(usize, &str)> = found.iter().map(|m| (m.start, m.text)).collect();
+        assert_eq!(found, vec![(0, "fünf"), (5, "EINS"), (9, "7")]);
+
+        // overlapping words that end together are all there, longest first
+        let lexicon = Lexicon::new().with_words([("teen", 13), ("seventeen", 17), ("n", 0)]);
+        let found: Vec<u32> = Matcher::new(&lexicon)
+            .find_all("seventeen")
+            .map(|m| m.value)
+            .collect();
+        assert_eq!(found, vec![0, 17, 13, 0]);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_find_all_has_the_first_and_last(
+            input in "[a-z0-9]{0,4}(one|two|six|seven|eight|nine|teen|[0-9a-z]){0,8}",
+        ) {
+            let lexicon = Lexicon::english().with_words([("seventeen", 17), ("teen", 13)]);
+            let found: Vec<DigitMatch> = Matcher::new(&lexicon).find_all(&input).collect();
+
+            for found in &found {
+                prop_assert_eq!(&input[found.start..found.end()], found.text);
+            }
+
+            let first = found
+                .iter()
+                .min_by_key(|m| (m.start, std::cmp::Reverse(m.text.len())))
+                .map(|m| m.value);
+            // the last to end is already the longest of those that end there
+            let last = found.iter().rev().max_by_key(|m| m.end()).map(|m| m.value);
+            prop_assert_eq!(first.zip(last), find_first_last(&input, &lexicon));
+        }
+
+        #[test]
+        fn prop_matcher_agrees_with_two_pointer_scan(
+            input in "[a-zA-Z0-9]{0,8}(one|two|six|seven|eight|nine|teen|[0-9a-z]){0,8}",
+            case_insensitive in any::<bool>(),
+        ) {
+            let mut lexicon = Lexicon::english().with_words([("seventeen", 17), ("teen", 13)]);
+            if case_insensitive {
+                lexicon = lexicon.case_insensitive();
+            }
+
+            prop_assert_eq!(
+                Matcher::new(&lexicon).find_first_last(&input),
+                find_first_last(&input, &lexicon)
+            );
+        }
+    }
+
+    #[test]
+    fn test_missing_digits() {
+        let input = "1abc2\nnope\nthree\n4\n\nx";
+        let lexicon = Lexicon::digits();
+
+        let err = get_puzzle_result(input, &lexicon, MissingDigits::Fail).unwrap_err();
+        assert_eq!(err.lines, vec![2, 3, 5, 6]);
+        assert_eq!(err.to_string(), "lines 2, 3, 5, 6 did not contain a digit");
+
+        assert_eq!(
+            get_puzzle_result(input, &lexicon, MissingDigits::Skip),
+            Ok(12 + 44)
+        );
+        assert_eq!(
+            get_puzzle_result("1\nthree", &Lexicon::english(), MissingDigits::Fail),
+            Ok(11 + 33)
+        );
+        assert_eq!(
+            get_puzzle_result("1\nthree", &lexicon, MissingDigits::Fail)
+                .unwrap_err()
+                .to_string(),
+            "line 2 did not contain a digit"
+        );
+    }
+
+    #[test]
+    fn test_missing_digits_are_numbered_as_in_the_file() {
+        let input = Day1::parse("\n\nnope\n1abc2\n\n").unwrap();
+        let err = Day1::part_1(&input).unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<MissingDigitsError>().unwrap().lines,
+            vec![1, 2, 3]
+        );
+        assert_eq!(err.to_string(), "lines 1, 2, 3 did not contain a digit");
+    }
+
+    #[test]
+    fn test_bytewise_compare_forwards() {
+        let input_str = "abcone923";
+        let first_slice = &input_str.as_bytes()[3..6];
+        assert!(bytewise_compare(first_slice, "one".as_bytes()));
+    }
+}
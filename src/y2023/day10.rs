@@ -0,0 +1,231 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::point::{Direction, Point};
+use crate::render::{Image, Rgb};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pipe {
+    Vertical,
+    Horizontal,
+    NorthToEast,
+    NorthToWest,
+    SouthToWest,
+    SouthToEast,
+    Ground,
+    StartingPosition,
+}
+
+impl TryFrom<char> for Pipe {
+    /// The character that isn't a pipe
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::NorthToEast),
+            'J' => Ok(Self::NorthToWest),
+            '7' => Ok(Self::SouthToWest),
+            'F' => Ok(Self::SouthToEast),
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::StartingPosition),
+            c => Err(c),
+        }
+    }
+}
+
+impl Pipe {
+    /// The directions this pipe connects to. The starting position could be any pipe, so it's open
+    /// in every direction.
+    fn openings(self) -> &'static [Direction] {
+        use Direction::*;
+
+        match self {
+            Self::Vertical => &[North, South],
+            Self::Horizontal => &[West, East],
+            Self::NorthToEast => &[North, East],
+            Self::NorthToWest => &[North, West],
+            Self::SouthToWest => &[South, West],
+            Self::SouthToEast => &[South, East],
+            Self::Ground => &[],
+            Self::StartingPosition => &Direction::CARDINAL,
+        }
+    }
+}
+
+impl fmt::Display for Pipe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Self::Vertical => '|',
+            Self::Horizontal => '-',
+            Self::NorthToEast => 'L',
+            Self::NorthToWest => 'J',
+            Self::SouthToWest => '7',
+            Self::SouthToEast => 'F',
+            Self::Ground => '.',
+            Self::StartingPosition => 'S',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+pub struct Graph {
+    grid: Grid<Pipe>,
+    start: Point,
+}
+
+impl Graph {
+    fn from_input(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(Day10::DAY, input, |c| {
+            Pipe::try_from(c).map_err(|c| format!("unrecognized pipe '{c}'"))
+        })?;
+
+        let start = grid
+            .position(|&pipe| pipe == Pipe::StartingPosition)
+            .ok_or_else(|| ParseError::new(Day10::DAY, input, input, "no starting position"))?;
+
+        Ok(Self { grid, start })
+    }
+
+    /// The positions the pipe at `pos` leads to, which are the neighbours it has an opening towards
+    /// that have an opening back.
+    fn connections(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        self.grid[pos]
+            .openings()
+            .iter()
+            .filter(move |&&direction| {
+                self.grid
+                    .get(pos + direction)
+                    .is_some_and(|pipe| pipe.openings().contains(&direction.reverse()))
+            })
+            .map(move |&direction| pos + direction)
+    }
+
+    /// Every position along the loop through the starting position, starting with it. Pipes that
+    /// are connected to the starting position but lead nowhere aren't part of it.
+    fn get_loop(&self) -> Result<Vec<Point>> {
+        self.connections(self.start)
+            .find_map(|first| self.walk_from(first))
+            .ok_or_else(|| eyre!("the pipes from the starting position don't loop back to it"))
+    }
+
+    /// Follows the pipes from the starting position through `first`, always on to the connection
+    /// that isn't where it just came from, until it's back at the start. Returns `None` if the pipes
+    /// dead-end before then.
+    fn walk_from(&self, first: Point) -> Option<Vec<Point>> {
+        let mut path = vec![self.start];
+        let (mut prev, mut pos) = (self.start, first);
+
+        while pos != self.start {
+            path.push(pos);
+            let next = self.connections(pos).find(|&next| next != prev)?;
+            (prev, pos) = (pos, next);
+        }
+
+        Some(path)
+    }
+
+    /// The pipes, with the loop drawn around the ones that are part of it, starting from the
+    /// starting position in green. There's nothing to draw if there's no loop.
+    fn render(&self) -> Option<Image> {
+        let mut path = self.get_loop().ok()?;
+        let on_loop: HashSet<Point> = path.iter().copied().collect();
+        path.push(self.start);
+
+        let image = Image::new(&self.grid, |pos, &pipe| match pipe {
+            Pipe::StartingPosition => Rgb::GREEN,
+            Pipe::Ground => Rgb::BLACK,
+            _ if on_loop.contains(&pos) => Rgb::GREY,
+            _ => Rgb::DARK_GREY,
+        });
+
+        Some(image.with_path(path, Rgb::RED))
+    }
+}
+
+fn solve_part_1(graph: &Graph) -> Result<usize> {
+    let loop_path = graph.get_loop()?;
+
+    Ok(loop_path.len() / 2)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 10;
+
+    type Parsed<'a> = Graph;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Graph::from_input(input.trim())
+    }
+
+    fn part_1(graph: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(solve_part_1(graph)?.into())
+    }
+
+    fn render(graph: &Self::Parsed<'_>) -> Option<Image> {
+        graph.render()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let err = Day10::parse(".S-7.\n.|x|.").err().unwrap();
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "unrecognized pipe 'x'");
+    }
+
+    #[test]
+    fn test_get_loop() {
+        let input = "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF";
+        let graph = Day10::parse(input).unwrap();
+
+        assert_eq!(graph.start, Point::new(1, 1));
+        assert_eq!(graph.grid.to_string(), format!("{input}\n"));
+        assert_eq!(
+            graph.get_loop().unwrap(),
+            vec![
+                Point::new(1, 1),
+                Point::new(1, 2),
+                Point::new(1, 3),
+                Point::new(2, 3),
+                Point::new(3, 3),
+                Point::new(3, 2),
+                Point::new(3, 1),
+                Point::new(2, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dangling_pipes_are_not_part_of_the_loop() {
+        // the pipes running west from S connect to it, but don't go anywhere
+        let input = ".......\n--S-7..\n..|.|..\n..L-J..\n.......";
+        let graph = Day10::parse(input).unwrap();
+
+        assert_eq!(graph.get_loop().unwrap().len(), 8);
+        assert_eq!(Day10::part_1(&graph).unwrap(), Answer::Number(4));
+
+        let graph = Day10::parse("..F7.\n--S|.\n.....").unwrap();
+        assert_eq!(
+            Day10::part_1(&graph).unwrap_err().to_string(),
+            "the pipes from the starting position don't loop back to it"
+        );
+        assert!(Day10::render(&graph).is_none());
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use color_eyre::eyre::WrapErr;
+use color_eyre::Result;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, line_ending, space1};
+use nom::combinator::{all_consuming, cut};
+use nom::multi::separated_list1;
+use nom::sequence::tuple;
+use nom::IResult;
+use rayon::prelude::*;
+
+use crate::error::ParseError;
+use crate::parse::{self, number, numbered};
+use crate::solution::{Answer, Solution};
+
+/// Represents the presence of a single color in a grab from the bag. Any word is a color, not just
+/// the red, green and blue of the puzzle.
+#[derive(Debug, PartialEq)]
+pub struct SingleColorGrab<'a> {
+    color: &'a str,
+    count: u32,
+}
+
+// A "game" represents a full game representing one or more grabs, each of which contain one or more
+// different sets of colored die.
+#[derive(Debug)]
+pub struct Game<'a> {
+    id: usize,
+    grabs: Vec<Vec<SingleColorGrab<'a>>>,
+}
+
+impl<'a> Game<'a> {
+    /// The fewest cubes of each color that the bag could have held for this game to be possible.
+    pub fn fewest_cubes(&self) -> HashMap<&'a str, u32> {
+        let mut fewest = HashMap::new();
+
+        for single_grab in self.grabs.iter().flatten() {
+            let count = fewest.entry(single_grab.color).or_insert(0);
+            *count = (*count).max(single_grab.count);
+        }
+
+        fewest
+    }
+}
+
+/// Parses a single color grab from an input such as "3 blue" -> Grab { 3, "blue" }
+fn parse_single_color_in_grab(input: &str) -> IResult<&str, SingleColorGrab<'_>> {
+    let (input, (count, _, color)) = tuple((number, space1, alpha1))(input)?;
+
+    Ok((input, SingleColorGrab { count, color }))
+}
+
+fn parse_all_colors_for_grab(input: &str) -> IResult<&str, Vec<SingleColorGrab<'_>>> {
+    separated_list1(tag(", "), cut(parse_single_color_in_grab))(input)
+}
+
+fn parse_game(input: &str) -> IResult<&str, Game<'_>> {
+    let (input, (id, grabs)) = numbered(
+        "Game",
+        separated_list1(tag("; "), parse_all_colors_for_grab),
+    )(input)?;
+
+    Ok((input, Game { id, grabs }))
+}
+
+impl<'a> TryFrom<&'a str> for Game<'a> {
+    type Error = nom::Err<nom::error::Error<&'a str>>;
+
+    /// Parses a string into a Game
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let (_, game) = all_consuming(parse_game)(input)?;
+
+        Ok(game)
+    }
+}
+
+/// How many cubes of each color are in the bag. There are none of any color it doesn't mention.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bag {
+    counts: HashMap<String, u32>,
+}
+
+impl Bag {
+    /// An empty bag.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The bag from the puzzle: 12 red cubes, 13 green cubes and 14 blue cubes.
+    pub fn standard() -> Self {
+        [("red", 12), ("green", 13), ("blue", 14)]
+            .into_iter()
+            .collect()
+    }
+
+    /// Parses a bag written the way the games write a grab, with the colors separated by commas or
+    /// newlines, e.g. "12 red, 13 green\n14 blue".
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let grabs = parse::parse_all(
+            Day2::DAY,
+            input,
+            separated_list1(
+                alt((tag(", "), line_ending)),
+                cut(parse_single_color_in_grab),
+            ),
+        )
+        .map_err(|e| e.in_year(Day2::YEAR))?;
+
+        Ok(grabs
+            .into_iter()
+            .map(|grab| (grab.color, grab.count))
+            .collect())
+    }
+
+    /// Loads a bag from a file in the format `Bag::parse` reads.
+    pub fn load(path: &Path) -> color_eyre::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("could not read a bag from {}", path.display()))?;
+
+        Self::parse(&contents)
+            .wrap_err_with(|| format!("could not parse the bag in {}", path.display()))
+    }
+
+    /// How many cubes of `color` are in the bag.
+    pub fn count(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    /// Whether the bag has at least as many cubes of each color as `cubes`.
+    pub fn holds(&self, cubes: &HashMap<&str, u32>) -> bool {
+        cubes
+            .iter()
+            .all(|(color, &count)| count <= self.count(color))
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Bag {
+    /// Builds a bag from colors and counts. A color that's given twice has the total of both counts.
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(iter: I) -> Self {
+        let mut counts = HashMap::new();
+        for (color, count) in iter {
+            *counts.entry(color.into()).or_insert(0) += count;
+        }
+
+        Self { counts }
+    }
+}
+
+/// Sums the IDS of the games where the number of cubes that the elf pulls out of the max are
+/// possible given the cubes in the bag.
+pub fn find_possible_games(games: &[Game<'_>], bag: &Bag) -> usize {
+    find_possible_games_for_bags(games, std::slice::from_ref(bag))[0]
+}
+
+/// Like `find_possible_games`, but for each of the bags at once, going over the games only once.
+/// The sums are in the same order as the bags.
+pub fn find_possible_games_for_bags(games: &[Game<'_>], bags: &[Bag]) -> Vec<usize> {
+    games
+        .par_iter()
+        .map(|game| {
+            let fewest = game.fewest_cubes();
+
+            bags.iter()
+                .map(|bag| match bag.holds(&fewest) {
+                    true => game.id,
+                    false => 0,
+                })
+                .collect::<Vec<usize>>()
+        })
+        .reduce(
+            || vec![0; bags.len()],
+            |mut sums, ids| {
+                sums.iter_mut().zip(ids).for_each(|(sum, id)| *sum += id);
+                sums
+            },
+        )
+}
+
+/// Computes the total sum power of the minimum number of red, green, and blue cubes required to make
+/// each game possible.
+pub fn sum_of_powers_of_fewest_cubes(games: &[Game<'_>]) -> u32 {
+    games
+        .par_iter()
+        .map(|game| {
+            let fewest = game.fewest_cubes();
+
+            ["red", "green", "blue"]
+                .iter()
+                .map(|color| fewest.get(color).copied().unwrap_or(0))
+                .product::<u32>()
+        })
+        .sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 2;
+
+    type Parsed<'a> = Vec<Game<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        // note: this is _not_ the fastest way to do this lol...we could definitely just regex for
+        // big numbers and probably be fine.  But I want to have FUN and use a PARSER COMBINATOR
+        // library and what not goddammit!
+        parse::parse_lines(Self::DAY, input, parse_game)
+    }
+
+    fn part_1(games: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(find_possible_games(games, &Bag::standard()).into())
+    }
+
+    fn part_2(games: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(sum_of_powers_of_fewest_cubes(games).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, two green";
+        let err = Day2::parse(input).unwrap_err();
+
+        assert_eq!(err.day, 2);
+        assert_eq!((err.line, err.column), (2, 17));
+        assert_eq!(err.snippet, "Game 2: 1 blue, two green");
+    }
+
+    #[test]
+    fn test_parse_single_color_in_grab() {
+        let input = "3 blue";
+        let (_, color) = parse_single_color_in_grab(input).unwrap();
+        assert_eq!(
+            color,
+            SingleColorGrab {
+                count: 3,
+                color: "blue"
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let game: Game = input.try_into().unwrap();
+
+        assert_eq!(game.id, 1);
+        assert_eq!(game.grabs.len(), 3);
+
+        assert_eq!(
+            game.grabs,
+            vec![
+                vec![
+                    SingleColorGrab {
+                        count: 3,
+                        color: "blue"
+                    },
+                    SingleColorGrab {
+                        count: 4,
+                        color: "red"
+                    }
+                ],
+                vec![
+                    SingleColorGrab {
+                        count: 1,
+                        color: "red"
+                    },
+                    SingleColorGrab {
+                        count: 2,
+                        color: "green"
+                    },
+                    SingleColorGrab {
+                        count: 6,
+                        color: "blue"
+                    }
+                ],
+                vec![SingleColorGrab {
+                    count: 2,
+                    color: "green"
+                }]
+            ]
+        )
+    }
+
+    #[test]
+    fn test_any_color() {
+        let game: Game = "Game 7: 2 purple, 1 red; 5 purple".try_into().unwrap();
+
+        assert_eq!(
+            game.fewest_cubes(),
+            HashMap::from([("purple", 5), ("red", 1)])
+        );
+        assert_eq!(find_possible_games(&[game], &Bag::standard()), 0);
+    }
+
+    #[test]
+    fn test_bag() {
+        let bag = Bag::parse("12 red, 13 green\n14 blue\n").unwrap();
+        assert_eq!(bag, Bag::standard());
+        assert_eq!(bag.count("blue"), 14);
+        assert_eq!(bag.count("purple"), 0);
+
+        let err = Bag::parse("12 red, lots green").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+
+        let path = std::env::temp_dir().join(format!("aoc-bag-{}.txt", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "3 purple\n1 red").unwrap();
+        let bag = Bag::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(bag, [("purple", 3), ("red", 1)].into_iter().collect());
+
+        assert!(Bag::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_many_bags() {
+        let games = Day2::parse(include_str!("../../examples/2023/day_2/example.txt")).unwrap();
+        let bags = [
+            Bag::standard(),
+            Bag::new(),
+            [("red", 20), ("green", 20), ("blue", 20)]
+                .into_iter()
+                .collect(),
+        ];
+
+        assert_eq!(
+            find_possible_games_for_bags(&games, &bags),
+            vec![8, 0, 1 + 2 + 3 + 4 + 5]
+        );
+        assert!(find_possible_games_for_bags(&games, &[]).is_empty());
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt;
+
+use color_eyre::Result;
+
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::render::{Image, Rgb};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cell {
+    Empty,
+    Digit(u32),
+    Symbol(char),
+}
+
+impl From<char> for Cell {
+    fn from(c: char) -> Self {
+        // For the purposes of this problem input, we can consider everything that's not a '.' or a
+        // number to be a "symbol"
+        match c {
+            '.' => Self::Empty,
+            c => c.to_digit(10).map_or(Self::Symbol(c), Self::Digit),
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "."),
+            Self::Digit(digit) => write!(f, "{digit}"),
+            Self::Symbol(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+/// A number in the schematic, which covers the cells from `start` to `end` (inclusive) of a row.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Number {
+    start: Point,
+    end: Point,
+    value: u32,
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid<Cell>,
+    numbers: Vec<Number>,
+    /// The index into `numbers` of the number covering each cell, if any.
+    number_at: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(Day3::DAY, input, |c| Ok(Cell::from(c)))?;
+
+        let numbers: Vec<Number> = grid
+            .rows()
+            .enumerate()
+            .flat_map(|(row, cells)| numbers_in_row(row, cells))
+            .collect();
+
+        let mut number_at = Grid::new(grid.width(), grid.height(), None);
+        for (idx, number) in numbers.iter().enumerate() {
+            for col in number.start.col..=number.end.col {
+                number_at[Point::new(number.start.row, col)] = Some(idx);
+            }
+        }
+
+        Ok(Self {
+            grid,
+            numbers,
+            number_at,
+        })
+    }
+
+    pub fn has_adjacent_symbol(&self, start: Point, end: Point) -> bool {
+        (start.col..=end.col).any(|col| {
+            self.grid
+                .surrounding(Point::new(start.row, col))
+                .any(|pos| matches!(self.grid[pos], Cell::Symbol(_)))
+        })
+    }
+
+    /// The product of the two numbers next to the gear at `pos`, or 0 if it isn't next to exactly
+    /// two numbers.
+    pub fn gear_ratio(&self, pos: Point) -> u32 {
+        let mut found: Vec<usize> = self
+            .grid
+            .surrounding(pos)
+            .filter_map(|pos| self.number_at[pos])
+            .collect();
+        found.sort_unstable();
+        found.dedup();
+
+        match found[..] {
+            [first, second] => self.numbers[first].value * self.numbers[second].value,
+            _ => 0,
+        }
+    }
+
+    pub fn sum_of_gear_ratios(&self) -> u32 {
+        self.grid
+            .iter()
+            .filter(|(_, cell)| **cell == Cell::Symbol('*'))
+            .map(|(pos, _)| self.gear_ratio(pos))
+            .sum()
+    }
+
+    pub fn sum_numbers_with_adjencent_symbols(&self) -> u32 {
+        self.numbers
+            .iter()
+            .filter(|number| self.has_adjacent_symbol(number.start, number.end))
+            .map(|number| number.value)
+            .sum()
+    }
+
+    /// The schematic with part numbers in green, the numbers that aren't in red and symbols in
+    /// yellow, apart from the gears, which are blue.
+    pub fn render(&self) -> Image {
+        Image::new(&self.grid, |pos, cell| match cell {
+            Cell::Empty => Rgb::DARK_GREY,
+            Cell::Digit(_) => {
+                let number = self.numbers[self.number_at[pos].expect("Expected a number here")];
+                match self.has_adjacent_symbol(number.start, number.end) {
+                    true => Rgb::GREEN,
+                    false => Rgb::RED,
+                }
+            }
+            Cell::Symbol('*') if self.gear_ratio(pos) > 0 => Rgb::BLUE,
+            Cell::Symbol(_) => Rgb::YELLOW,
+        })
+    }
+}
+
+/// Given a row like "123...456..." returns the numbers 123 and 456, along with where they start and
+/// end.
+fn numbers_in_row(row: usize, cells: &[Cell]) -> Vec<Number> {
+    let mut numbers: Vec<Number> = Vec::new();
+    let mut current: Option<Number> = None;
+
+    for (col, cell) in cells.iter().enumerate() {
+        match (cell, current.as_mut()) {
+            (Cell::Digit(digit), Some(number)) => {
+                number.value = number.value * 10 + digit;
+                number.end = Point::from((row, col));
+            }
+            (Cell::Digit(digit), None) => {
+                current = Some(Number {
+                    start: Point::from((row, col)),
+                    end: Point::from((row, col)),
+                    value: *digit,
+                })
+            }
+            (_, _) => numbers.extend(current.take()),
+        }
+    }
+
+    numbers.extend(current);
+    numbers
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 3;
+
+    type Parsed<'a> = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        Schematic::from_input(input.trim())
+    }
+
+    fn part_1(schematic: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(schematic.sum_numbers_with_adjencent_symbols().into())
+    }
+
+    fn part_2(schematic: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(schematic.sum_of_gear_ratios().into())
+    }
+
+    fn render(schematic: &Self::Parsed<'_>) -> Option<Image> {
+        Some(schematic.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers_from_line() {
+        let cells: Vec<Cell> = "467..114..$.".chars().map(Cell::from).collect();
+
+        let values: Vec<u32> = numbers_in_row(123, &cells)
+            .iter()
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(values, vec![467, 114]);
+        assert_eq!(numbers_in_row(123, &cells)[1].start, Point::new(123, 5));
+        assert_eq!(numbers_in_row(123, &cells)[1].end, Point::new(123, 7));
+        assert_eq!(cells[10], Cell::Symbol('$'));
+
+        // a number running into the end of the row still counts
+        assert_eq!(numbers_in_row(0, &cells[..2])[0].value, 46);
+    }
+
+    #[test]
+    fn test_grid_semantics() {
+        let schematic = Schematic::from_input("467\n..*").unwrap();
+
+        assert_eq!(schematic.grid[Point::new(0, 0)], Cell::Digit(4));
+        assert_eq!(schematic.grid[Point::new(1, 2)], Cell::Symbol('*'));
+        assert_eq!(schematic.grid.get(Point::new(4, 5)), None);
+        assert_eq!(schematic.number_at[Point::new(0, 2)], Some(0));
+        assert_eq!(schematic.number_at[Point::new(1, 0)], None);
+        assert_eq!(schematic.grid.to_string(), "467\n..*\n");
+
+        assert!(Schematic::from_input("467\n..").is_err());
+    }
+
+    #[test]
+    fn test_has_adjancent_symbol() {
+        let input = "467";
+        let schematic = Schematic::from_input(input).unwrap();
+        assert!(!schematic.has_adjacent_symbol(Point::new(0, 0), Point::new(0, 2)));
+
+        let input = "467$";
+        let schematic = Schematic::from_input(input).unwrap();
+        assert!(schematic.has_adjacent_symbol(Point::new(0, 0), Point::new(0, 2)));
+
+        let input = r#"
+...123...
+...467...
+......$..
+......123
+.........
+.....123.
+....@....
+        "#
+        .trim();
+
+        let schematic = Schematic::from_input(input).unwrap();
+        assert!(schematic.has_adjacent_symbol(Point::new(1, 3), Point::new(1, 5)));
+        assert!(!schematic.has_adjacent_symbol(Point::new(0, 3), Point::new(0, 5)));
+        assert!(schematic.has_adjacent_symbol(Point::new(3, 6), Point::new(3, 8)));
+        assert!(schematic.has_adjacent_symbol(Point::new(5, 5), Point::new(5, 7)));
+    }
+
+    #[test]
+    fn test_has_adjancent_symbol2() {
+        let input = r#"
+......755.
+...$.*....
+        "#
+        .trim();
+
+        let schematic = Schematic::from_input(input).unwrap();
+        assert!(schematic.has_adjacent_symbol(Point::new(1, 6), Point::new(1, 8)));
+        assert!(schematic.has_adjacent_symbol(Point::new(0, 6), Point::new(0, 8)));
+        assert_eq!(schematic.gear_ratio(Point::new(1, 5)), 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let schematic = Schematic::from_input("12*3\n4...").unwrap();
+        let svg = schematic.render().to_svg();
+
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#40c040\""));
+        assert!(svg.contains("<rect x=\"2\" y=\"0\" width=\"1\" height=\"1\" fill=\"#4080e0\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"1\" width=\"1\" height=\"1\" fill=\"#e04040\""));
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::{HashMap, HashSet};
+
+use color_eyre::eyre::bail;
+use color_eyre::Result;
+use nom::bytes::complete::tag;
+use nom::character::complete::space0;
+use nom::sequence::{delimited, separated_pair};
+use nom::IResult;
+
+use crate::error::ParseError;
+use crate::parse::{self, numbered, numbers};
+use crate::solution::{Answer, Solution};
+
+fn parse_card_line(input: &str) -> IResult<&str, (u32, Vec<u32>, Vec<u32>)> {
+    let (input, (card_id, (winning, has))) = numbered(
+        "Card",
+        separated_pair(numbers, delimited(space0, tag("|"), space0), numbers),
+    )(input)?;
+
+    Ok((input, (card_id, winning, has)))
+}
+
+fn count_winning_numbers(winning: Vec<u32>, has: Vec<u32>) -> u32 {
+    let mut winning_set: HashSet<u32> = HashSet::new();
+    for score in winning {
+        winning_set.insert(score);
+    }
+
+    let mut x = 0;
+    for score in has {
+        if winning_set.contains(&score) {
+            x += 1;
+        }
+    }
+
+    x
+}
+
+fn win_card(
+    card_id: u32,
+    current_index: usize,
+    win_count: u32,
+    cards: &[(u32, u32)],
+    store: &mut HashMap<u32, u32>,
+    _depth: u32,
+) -> Result<()> {
+    // Increment the number of wins for that card
+    store
+        .entry(card_id)
+        .and_modify(|wins| {
+            *wins += 1;
+        })
+        .or_insert(1);
+
+    for i in (current_index + 1)..(current_index + (win_count as usize) + 1) {
+        // AOC promised me that it will never make me copy a card past the end of the table
+        let Some((next_card_id, next_win_count)) = cards.get(i) else {
+            bail!("card {card_id} copies cards past the end of the table");
+        };
+
+        win_card(*next_card_id, i, *next_win_count, cards, store, _depth + 1)?;
+    }
+
+    Ok(())
+}
+
+/// Each card is a (card id, count of winning numbers) pair.
+pub fn sum_recursive_won_scratchcards(cards: &[(u32, u32)]) -> Result<u32> {
+    // Maps from a card ID to how many times it's been won
+    let mut store: HashMap<u32, u32> = HashMap::new();
+
+    for (idx, (card_id, win_count)) in cards.iter().enumerate() {
+        win_card(*card_id, idx, *win_count, cards, &mut store, 0)?;
+    }
+
+    Ok(store.values().sum())
+}
+
+pub fn sum_winning_scores(cards: &[(u32, u32)]) -> u32 {
+    cards
+        .iter()
+        .map(|(_, win_count)| match win_count {
+            0 => 0,
+            _ => 1 << (win_count - 1),
+        })
+        .sum()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 4;
+
+    type Parsed<'a> = Vec<(u32, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let cards = parse::parse_lines(Self::DAY, input, parse_card_line)?;
+
+        Ok(cards
+            .into_iter()
+            .map(|(card_id, winning, has)| (card_id, count_winning_numbers(winning, has)))
+            .collect())
+    }
+
+    fn part_1(cards: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(sum_winning_scores(cards).into())
+    }
+
+    fn part_2(cards: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(sum_recursive_won_scratchcards(cards)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30";
+        let err = Day4::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 12));
+        assert_eq!(err.snippet, "Card 2: 13 x2 | 61 30");
+    }
+
+    #[test]
+    fn test_copies_past_the_end() {
+        let cards = Day4::parse("Card 1: 1 2 | 2\nCard 2: 1 | 1").unwrap();
+        let err = Day4::part_2(&cards).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "card 2 copies cards past the end of the table"
+        );
+    }
+}
@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::{bail, eyre};
+use color_eyre::Result;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, not_line_ending, space1},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+
+use crate::error::ParseError;
+use crate::interval::{Interval, IntervalSet, OffsetMap};
+use crate::parse::{self, blank_line, labelled, lines, sections};
+use crate::solution::{Answer, Solution};
+
+// e.g. "seed, soil" represents a single entry in the almanac
+type InputMapKey<'a> = (&'a str, &'a str);
+// (dest, source, range length)
+type AlmanacRange = (i64, i64, i64);
+
+// Represents the initial input in a very basic parsed format
+#[derive(Debug, Default)]
+pub struct Input<'a> {
+    seeds: Vec<i64>,
+    maps: HashMap<InputMapKey<'a>, Vec<AlmanacRange>>,
+    // the maps from seed to location in order, as functions that can map whole ranges of values at
+    // once
+    offset_maps: Vec<OffsetMap>,
+}
+
+const ELEMENT_ORDER: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+impl<'a> Input<'a> {
+    fn pre_process(&mut self) {
+        self.offset_maps = ELEMENT_ORDER
+            .windows(2)
+            .map(|pair| {
+                let mut map = OffsetMap::new();
+
+                for (dest, source, range) in
+                    self.maps.get(&(pair[0], pair[1])).into_iter().flatten()
+                {
+                    // e.g. "52 50 48" moves the 48 values from 50 on up by 2
+                    map.insert(Interval::with_len(*source, *range), dest - source);
+                }
+
+                map
+            })
+            .collect();
+    }
+
+    fn get_location_for_seed(&self, seed: i64) -> i64 {
+        self.offset_maps
+            .iter()
+            .fold(seed, |value, map| map.get(value))
+    }
+
+    // the seeds line read as pairs of (start, length) ranges of seeds, like part 2 wants
+    fn seed_ranges(&self) -> Result<IntervalSet> {
+        self.seeds
+            .chunks(2)
+            .map(|pair| match pair {
+                [start, len] if start.checked_add(*len).is_some() => {
+                    Ok(Interval::with_len(*start, *len))
+                }
+                [start, len] => bail!("the range of {len} seeds from {start} is out of range"),
+                _ => bail!("the last range of seeds has no length"),
+            })
+            .collect()
+    }
+}
+
+/// A number in the almanac. They're never negative, but they have to fit in an i64 so that ranges
+/// of them can be shifted either way.
+fn value(input: &str) -> IResult<&str, i64> {
+    let (rest, digits) = digit1(input)?;
+
+    match digits.parse() {
+        Ok(value) => Ok((rest, value)),
+        // it's certainly a number, so there's nothing else to backtrack and try instead
+        Err(_) => Err(nom::Err::Failure(Error::new(input, ErrorKind::TooLarge))),
+    }
+}
+
+fn element_parser(input: &str) -> IResult<&str, &str> {
+    alt((
+        tag("seed"),
+        tag("soil"),
+        tag("fertilizer"),
+        tag("water"),
+        tag("light"),
+        tag("temperature"),
+        tag("humidity"),
+        tag("location"),
+    ))(input)
+}
+
+fn map_line_parser(input: &str) -> IResult<&str, InputMapKey<'_>> {
+    let (input, (from, _, to, _)) =
+        tuple((element_parser, tag("-to-"), element_parser, not_line_ending))(input)?;
+
+    Ok((input, (from, to)))
+}
+
+fn map_num_line(input: &str) -> IResult<&str, AlmanacRange> {
+    let (input, (num1, _, num2, _)) = tuple((value, space1, value, space1))(input)?;
+    let (rest, num3) = value(input)?;
+
+    // both ranges have to end somewhere an i64 can still get to
+    match num1.max(num2).checked_add(num3) {
+        Some(_) => Ok((rest, (num1, num2, num3))),
+        None => Err(nom::Err::Failure(Error::new(input, ErrorKind::TooLarge))),
+    }
+}
+
+fn map_parser(input: &str) -> IResult<&str, (InputMapKey<'_>, Vec<AlmanacRange>)> {
+    let (input, (map_line, num_list)) =
+        separated_pair(map_line_parser, line_ending, lines(map_num_line))(input)?;
+
+    Ok((input, (map_line, num_list)))
+}
+
+// I realize that this is the stupidest way to do this but listen, I wanted to come out of AOC this
+// year knowing the nom parser combinator library better.
+fn parse_input<'a>(input: &'a str) -> IResult<&'a str, Input<'a>> {
+    let (input, (seeds, maps)) = separated_pair(
+        labelled("seeds", separated_list1(space1, value)),
+        blank_line,
+        sections(map_parser),
+    )(input)?;
+    let maps: HashMap<InputMapKey<'a>, Vec<AlmanacRange>> = maps.into_iter().collect();
+
+    Ok((
+        input,
+        Input {
+            seeds,
+            maps,
+            offset_maps: Vec::new(),
+        },
+    ))
+}
+
+/// Checks what the parser can't see on its own: that there's a map for every step from seed to
+/// location. (The seeds are only read as ranges by part 2, so that's where they're checked.)
+fn validate(input: &str, processor: &Input<'_>) -> Result<(), ParseError> {
+    let error = |at, message: &str| ParseError::new(Day5::DAY, input, at, message);
+
+    for pair in ELEMENT_ORDER.windows(2) {
+        if !processor.maps.contains_key(&(pair[0], pair[1])) {
+            let end = &input[input.trim_end().len()..];
+            return Err(error(
+                end,
+                &format!("missing the {}-to-{} map", pair[0], pair[1]),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn find_lowest_location_number(processor: &Input<'_>) -> Option<i64> {
+    processor
+        .seeds
+        .iter()
+        .map(|&seed| processor.get_location_for_seed(seed))
+        .min()
+}
+
+pub fn find_lowest_location_for_seed_ranges(processor: &Input<'_>) -> Result<i64> {
+    // there are billions of seeds, but only a handful of ranges of them, and each map only ever
+    // splits a range where one of its pieces starts or ends
+    let locations = processor
+        .offset_maps
+        .iter()
+        .fold(processor.seed_ranges()?, |ranges, map| map.map_set(&ranges));
+
+    locations
+        .min()
+        .ok_or_else(|| eyre!("every range of seeds is empty"))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 5;
+
+    type Parsed<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let mut processor = parse::parse_all(Self::DAY, input, parse_input)?;
+        validate(input, &processor)?;
+        processor.pre_process();
+
+        Ok(processor)
+    }
+
+    fn part_1(processor: &Self::Parsed<'_>) -> Result<Answer> {
+        let location =
+            find_lowest_location_number(processor).ok_or_else(|| eyre!("there are no seeds"))?;
+
+        Ok(location.into())
+    }
+
+    fn part_2(processor: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(find_lowest_location_for_seed_ranges(processor)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EXAMPLE_INPUT: &str = include_str!("../../examples/2023/day_5/example.txt");
+
+    // e.g. ("seed", "soil") = "give me the map from seed values to soil values"
+    fn offset_map<'p>(processor: &'p Input<'_>, key: InputMapKey<'_>) -> &'p OffsetMap {
+        let idx = ELEMENT_ORDER
+            .windows(2)
+            .position(|pair| (pair[0], pair[1]) == key)
+            .unwrap();
+
+        &processor.offset_maps[idx]
+    }
+
+    #[test]
+    fn test_map_line_parser() {
+        let input = "humidity-to-location map:\n";
+        let (_, map_line) = map_line_parser(input).unwrap();
+        assert_eq!(map_line, ("humidity", "location"));
+    }
+
+    #[test]
+    fn test_map_parser() {
+        let input = r#"
+humidity-to-location map:
+1 2 3
+2 3 4
+"#;
+        let (_, ((from, to), vecs)) = map_parser(input.trim()).unwrap();
+
+        assert_eq!(from, "humidity");
+        assert_eq!(to, "location");
+        assert_eq!(vecs, vec![(1, 2, 3), (2, 3, 4)]);
+    }
+
+    #[test]
+    fn test_multiple_maps() {
+        let input = r#"
+seeds: 1 2 3
+
+seed-to-soil map:
+1 2 3
+2 3 4
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+        "#;
+
+        let (_, result) = parse_input(input.trim()).unwrap();
+
+        let first_line = result.maps.get(&("seed", "soil")).unwrap().first().unwrap();
+        assert_eq!(*first_line, (1, 2, 3));
+
+        let first_line = result
+            .maps
+            .get(&("soil", "fertilizer"))
+            .unwrap()
+            .first()
+            .unwrap();
+        assert_eq!(*first_line, (0, 15, 37));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n4 5\n";
+        let err = Day5::parse(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.message, "unexpected trailing input");
+    }
+
+    #[test]
+    fn test_get_corresponding() {
+        let (_, mut processor) = parse_input(EXAMPLE_INPUT.trim()).unwrap();
+        processor.pre_process();
+
+        let soil_for_seed = offset_map(&processor, ("seed", "soil")).get(79);
+        assert_eq!(soil_for_seed, 81);
+
+        let soil_for_seed = offset_map(&processor, ("seed", "soil")).get(14);
+        assert_eq!(soil_for_seed, 14);
+
+        let soil_for_seed = offset_map(&processor, ("seed", "soil")).get(55);
+        assert_eq!(soil_for_seed, 57);
+
+        let fertilizer_for_soil = offset_map(&processor, ("soil", "fertilizer")).get(14);
+        assert_eq!(fertilizer_for_soil, 53);
+    }
+
+    #[test]
+    fn test_get_corresponding_reverse() {
+        let (_, mut processor) = parse_input(EXAMPLE_INPUT.trim()).unwrap();
+        processor.pre_process();
+
+        let reverse = |key| offset_map(&processor, key).inverse();
+
+        let humidity_for_location = reverse(("humidity", "location")).get(46);
+        assert_eq!(humidity_for_location, 46);
+
+        let temperature_for_humidity = reverse(("temperature", "humidity")).get(46);
+        assert_eq!(temperature_for_humidity, 45);
+
+        let light_for_temperature = reverse(("light", "temperature")).get(45);
+        assert_eq!(light_for_temperature, 77);
+    }
+
+    #[test]
+    fn test_seed_ranges() {
+        let (_, mut processor) = parse_input(EXAMPLE_INPUT.trim()).unwrap();
+        processor.pre_process();
+
+        assert_eq!(
+            processor.seed_ranges().unwrap(),
+            IntervalSet::from_iter([Interval::with_len(79, 14), Interval::with_len(55, 13)])
+        );
+        assert_eq!(
+            find_lowest_location_for_seed_ranges(&processor).unwrap(),
+            46
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        let maps = EXAMPLE_INPUT.split_once("\n\n").unwrap().1;
+        let with_seeds = |seeds: &str| format!("seeds: {seeds}\n\n{maps}");
+
+        let input = with_seeds("79 9223372036854775808");
+        let err = Day5::parse(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 11));
+        assert_eq!(err.message, "number out of range");
+
+        let input = EXAMPLE_INPUT.replace("52 50 48", "52 50 9223372036854775800");
+        let err = Day5::parse(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (5, 7));
+        assert_eq!(err.message, "number out of range");
+
+        let input = EXAMPLE_INPUT.replace("water-to-light", "water-to-location");
+        let err = Day5::parse(&input).unwrap_err();
+        assert_eq!(err.message, "missing the water-to-light map");
+    }
+
+    #[test]
+    fn test_seeds_that_are_not_ranges() {
+        let maps = EXAMPLE_INPUT.split_once("\n\n").unwrap().1;
+        let with_seeds = |seeds: &str| format!("seeds: {seeds}\n\n{maps}");
+
+        // only part 2 reads the seeds as ranges, so only part 2 minds that they don't pair up
+        let input = with_seeds("79 14 55");
+        let processor = Day5::parse(&input).unwrap();
+        assert_eq!(Day5::part_1(&processor).unwrap(), Answer::Number(43));
+        assert_eq!(
+            Day5::part_2(&processor).unwrap_err().to_string(),
+            "the last range of seeds has no length"
+        );
+
+        let input = with_seeds("79 14 55 9223372036854775800");
+        let processor = Day5::parse(&input).unwrap();
+        assert!(Day5::part_1(&processor).is_ok());
+        assert_eq!(
+            Day5::part_2(&processor).unwrap_err().to_string(),
+            "the range of 9223372036854775800 seeds from 55 is out of range"
+        );
+    }
+
+    #[test]
+    fn test_zero_length_ranges() {
+        let input = EXAMPLE_INPUT.replace("52 50 48", "52 50 0");
+        let processor = Day5::parse(&input).unwrap();
+        // seed 79 is no longer moved up by 2 on its way to being soil
+        assert_eq!(offset_map(&processor, ("seed", "soil")).get(79), 79);
+
+        let input = EXAMPLE_INPUT.replace("seeds: 79 14 55 13", "seeds: 79 0 55 0");
+        let processor = Day5::parse(&input).unwrap();
+        // part 1 still reads the lengths as seeds, and seed 0 ends up at location 22
+        assert_eq!(Day5::part_1(&processor).unwrap(), Answer::Number(22));
+        assert_eq!(
+            Day5::part_2(&processor).unwrap_err().to_string(),
+            "every range of seeds is empty"
+        );
+    }
+}
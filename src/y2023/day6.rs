@@ -0,0 +1,167 @@
+use color_eyre::Result;
+use nom::bytes::complete::take;
+use nom::character::complete::{line_ending, space0};
+use nom::combinator::map_res;
+use nom::error::{Error, ErrorKind};
+use nom::multi::many1;
+use nom::sequence::{separated_pair, terminated};
+use nom::IResult;
+
+use crate::error::ParseError;
+use crate::parse::{self, labelled, numbers};
+use crate::solution::{Answer, Solution};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(u64, u64)>> {
+    let (input, (times, distances)) = separated_pair(
+        labelled("Time", numbers::<u64>),
+        line_ending,
+        labelled("Distance", numbers::<u64>),
+    )(input)?;
+    let times_distances: Vec<(u64, u64)> = times
+        .iter()
+        .copied()
+        .zip(distances.iter().copied())
+        .collect();
+
+    Ok((input, times_distances))
+}
+
+/// Parses a single integer from the input, e.g. given "123" will return "1" as a u64.
+fn parse_single_integer(input: &str) -> IResult<&str, u64> {
+    let (input, digit) = map_res(take(1_usize), |c: &str| c.parse::<u64>())(input)?;
+    Ok((input, digit))
+}
+
+/// A number with spaces between its digits, e.g. "7  15   30" as 71530. A number too big for a u64
+/// is an error pointing at where it starts.
+fn parse_spaced_integer(input: &str) -> IResult<&str, u64> {
+    let (rest, digits) = many1(terminated(parse_single_integer, space0))(input)?;
+
+    match digits
+        .iter()
+        .try_fold(0u64, |acc, x| acc.checked_mul(10)?.checked_add(*x))
+    {
+        Some(number) => Ok((rest, number)),
+        None => Err(nom::Err::Failure(Error::new(input, ErrorKind::TooLarge))),
+    }
+}
+
+fn parse_input_part_2(input: &str) -> IResult<&str, Vec<(u64, u64)>> {
+    let (input, (time, distance)) = separated_pair(
+        labelled("Time", parse_spaced_integer),
+        line_ending,
+        labelled("Distance", parse_spaced_integer),
+    )(input)?;
+
+    Ok((input, vec![(time, distance)]))
+}
+
+fn get_num_ways_to_solve(races: &[(u64, u64)]) -> u64 {
+    let mut final_result: u64 = 1;
+
+    for (time, record_distance) in races.iter() {
+        // travel_time = time - hold
+        // distance_traveled = hold * travel_time
+        // (a distance too big for a u64 certainly beats the record)
+        let beats_record = |hold: u64| {
+            hold.checked_mul(time - hold)
+                .is_none_or(|distance_traveled| distance_traveled > *record_distance)
+        };
+
+        // its inefficient to check every hold/speed combination so we can just use two pointers
+        // from each end until each "beats" the record and then the difference is the number of ways
+        // we can beat the record
+        let mut hold_lower_bound: u64 = 1;
+
+        // walk up from the beginning
+        while hold_lower_bound < *time && !beats_record(hold_lower_bound) {
+            hold_lower_bound += 1;
+        }
+
+        // no way of holding the button beats the record (or there's no time to hold it at all)
+        if hold_lower_bound >= *time {
+            return 0;
+        }
+
+        // the lower bound beats the record, so walking down from the end stops there at the latest
+        let mut hold_upper_bound: u64 = time - 1;
+        while !beats_record(hold_upper_bound) {
+            hold_upper_bound -= 1;
+        }
+
+        let num_ways_to_beat_record = (hold_upper_bound + 1) - hold_lower_bound;
+        final_result *= num_ways_to_beat_record;
+    }
+
+    final_result
+}
+
+/// The races as read for part 1 (one race per column) and for part 2 (the columns' digits
+/// concatenated into a single race).
+#[derive(Debug)]
+pub struct Races {
+    part_1: Vec<(u64, u64)>,
+    part_2: Vec<(u64, u64)>,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 6;
+
+    type Parsed<'a> = Races;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let part_1 = parse::parse_all(Self::DAY, input, parse_input)?;
+        let part_2 = parse::parse_all(Self::DAY, input, parse_input_part_2)?;
+
+        Ok(Races { part_1, part_2 })
+    }
+
+    fn part_1(races: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(get_num_ways_to_solve(&races.part_1).into())
+    }
+
+    fn part_2(races: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(get_num_ways_to_solve(&races.part_2).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/2023/day_6/example.txt");
+
+    #[test]
+    fn test_parse_error_location() {
+        let err = Day6::parse("Time:      7  15   30\nDistance:  9  4O  200").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 16));
+
+        // each number fits on its own, but not once they're run together for part 2
+        let err = Day6::parse("Time: 99999999999 9999999999\nDistance: 1 2").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        assert_eq!(err.message, "number out of range");
+    }
+
+    #[test]
+    fn test_unbeatable_races() {
+        assert_eq!(get_num_ways_to_solve(&[(7, 9), (3, 100)]), 0);
+        assert_eq!(get_num_ways_to_solve(&[(0, 0)]), 0);
+        assert_eq!(get_num_ways_to_solve(&[(7, 9), (30, 200)]), 4 * 9);
+
+        let races = Day6::parse("Time: 3\nDistance: 100").unwrap();
+        assert_eq!(Day6::part_1(&races).unwrap(), Answer::Number(0));
+    }
+
+    #[test]
+    fn test_part_2_parse() {
+        let (_, part_2_parsed) = parse_input_part_2(INPUT.trim()).unwrap();
+
+        let (time, distance) = part_2_parsed.first().unwrap();
+        assert_eq!(*time, 71530);
+        assert_eq!(*distance, 940200);
+    }
+}
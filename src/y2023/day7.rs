@@ -1,10 +1,16 @@
-use nom::character::complete::{alphanumeric1, newline, space1, u64};
-use nom::multi::separated_list1;
+use color_eyre::Result;
+use nom::bytes::complete::take_while_m_n;
+use nom::character::complete::space1;
+use nom::combinator::cut;
 use nom::sequence::separated_pair;
 use nom::IResult;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use crate::error::ParseError;
+use crate::parse::{self, lines, number};
+use crate::solution::{Answer, Solution};
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum HandType {
     FiveOfAKind,
@@ -107,45 +113,33 @@ impl HandType {
     }
 }
 
-#[derive(Debug)]
-struct GameEntry<'a> {
+#[derive(Debug, Clone)]
+pub struct GameEntry<'a> {
     hand: &'a str,
     bid: u64,
     typ: HandType,
 }
 
-fn parse_hand_line(input: &str) -> IResult<&str, GameEntry<'_>> {
-    let (input, (hand, bid)) = separated_pair(alphanumeric1, space1, u64)(input)?;
-
-    Ok((
-        input,
-        GameEntry {
-            hand,
-            bid,
-            typ: HandType::part_1_from(hand),
-        },
-    ))
+fn is_card(c: char) -> bool {
+    "AKQJT98765432".contains(c)
 }
 
-fn parse_hand_line_part2(input: &str) -> IResult<&str, GameEntry<'_>> {
-    let (input, (hand, bid)) = separated_pair(alphanumeric1, space1, u64)(input)?;
+fn parse_hand_line(input: &str) -> IResult<&str, GameEntry<'_>> {
+    let (input, (hand, bid)) =
+        separated_pair(take_while_m_n(5, 5, is_card), space1, number)(input)?;
 
     Ok((
         input,
         GameEntry {
             hand,
             bid,
-            typ: HandType::part_2_from(hand),
+            typ: HandType::part_1_from(hand),
         },
     ))
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<GameEntry<'_>>> {
-    separated_list1(newline, parse_hand_line)(input)
-}
-
-fn parse_input_part2(input: &str) -> IResult<&str, Vec<GameEntry<'_>>> {
-    separated_list1(newline, parse_hand_line_part2)(input)
+    lines(cut(parse_hand_line))(input)
 }
 
 fn get_winnings(entries: &mut [GameEntry<'_>], scores: &HashMap<char, u64>) -> u64 {
@@ -192,8 +186,8 @@ fn get_winnings(entries: &mut [GameEntry<'_>], scores: &HashMap<char, u64>) -> u
     result
 }
 
-pub fn solve_part_1(input: &str) -> u64 {
-    let (_, mut entries) = parse_input(input.trim()).unwrap();
+fn solve_part_1(entries: &[GameEntry<'_>]) -> u64 {
+    let mut entries = entries.to_vec();
     let mut scores: HashMap<char, u64> = HashMap::new();
 
     scores.insert('A', 13);
@@ -213,8 +207,15 @@ pub fn solve_part_1(input: &str) -> u64 {
     get_winnings(&mut entries, &scores)
 }
 
-pub fn solve_part_2(input: &str) -> u64 {
-    let (_, mut entries) = parse_input_part2(input.trim()).unwrap();
+fn solve_part_2(entries: &[GameEntry<'_>]) -> u64 {
+    // jokers are wild in part 2, so every hand needs to be re-typed
+    let mut entries = entries
+        .iter()
+        .map(|entry| GameEntry {
+            typ: HandType::part_2_from(entry.hand),
+            ..*entry
+        })
+        .collect::<Vec<_>>();
     let mut scores: HashMap<char, u64> = HashMap::new();
     scores.insert('A', 13);
     scores.insert('K', 12);
@@ -233,17 +234,32 @@ pub fn solve_part_2(input: &str) -> u64 {
     get_winnings(&mut entries, &scores)
 }
 
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 7;
+
+    type Parsed<'a> = Vec<GameEntry<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse::parse_all(Self::DAY, input, parse_input)
+    }
+
+    fn part_1(entries: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(solve_part_1(entries).into())
+    }
+
+    fn part_2(entries: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(solve_part_2(entries).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = r#"
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483
-    "#;
+    const INPUT: &str = include_str!("../../examples/2023/day_7/example.txt");
 
     #[test]
     fn test_parse_entries() {
@@ -261,12 +277,11 @@ QQQJA 483
     }
 
     #[test]
-    fn test_part_1() {
-        assert_eq!(solve_part_1(INPUT.trim()), 6440);
-    }
+    fn test_parse_error_location() {
+        let err = Day7::parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
 
-    #[test]
-    fn test_part_2() {
-        assert_eq!(solve_part_2(INPUT.trim()), 5905);
+        let err = Day7::parse("32T3K 765\nT55J55 684").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
     }
 }
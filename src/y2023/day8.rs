@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::{bail, eyre};
+use color_eyre::Result;
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::alphanumeric1,
+    combinator::cut,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::error::ParseError;
+use crate::graph::{self, Graph};
+use crate::math;
+use crate::parse::{self, blank_line, lines};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Instructions<'a> {
+    directions: Vec<char>,
+    map: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+fn parse_key_value_line(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    let (input, key) = alphanumeric1(input)?;
+    let (input, _) = tag(" = (")(input)?;
+    let (input, (first, second)) = separated_pair(alphanumeric1, tag(", "), alphanumeric1)(input)?;
+    let (input, _) = tag(")")(input)?;
+
+    Ok((input, (key, (first, second))))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Instructions<'_>> {
+    let (input, (first_line, lines)) = separated_pair(
+        take_while1(|c| c == 'L' || c == 'R'),
+        blank_line,
+        lines(cut(parse_key_value_line)),
+    )(input)?;
+    let directions = first_line.chars().collect::<Vec<char>>();
+
+    let map = lines.into_iter().collect::<HashMap<&str, (&str, &str)>>();
+
+    let instructions = Instructions { directions, map };
+
+    Ok((input, instructions))
+}
+
+impl<'a> Instructions<'a> {
+    /// Follows the direction at `direction_idx` from `node`, giving the node that leads to and the
+    /// index of the direction to follow next, or `None` if `node` isn't defined.
+    fn step(&self, node: &str, direction_idx: usize) -> Option<(&'a str, usize)> {
+        let &(left, right) = self.map.get(node)?;
+        let next = match self.directions[direction_idx] {
+            'R' => right,
+            'L' => left,
+            direction => panic!("Unknown direction: {}", direction),
+        };
+
+        Some((next, (direction_idx + 1) % self.directions.len()))
+    }
+}
+
+/// The network as it's walked: each node paired with how far through the directions the walk is
+/// leads to exactly one other.
+impl<'a> Graph<(&'a str, usize)> for Instructions<'a> {
+    fn neighbours(
+        &self,
+        &(node, direction_idx): &(&'a str, usize),
+    ) -> impl IntoIterator<Item = (&'a str, usize)> {
+        self.step(node, direction_idx)
+    }
+}
+
+fn solve_part_1(instructions: &Instructions<'_>) -> Result<u64> {
+    // inputs that are only for part 2 don't have to have an AAA
+    if !instructions.map.contains_key("AAA") {
+        bail!("there's no AAA node to start from");
+    }
+
+    graph::bfs(instructions, ("AAA", 0))
+        .find(|&((node, _), _)| node == "ZZZ")
+        .map(|(_, steps)| steps)
+        .ok_or_else(|| eyre!("ZZZ can't be reached from AAA"))
+}
+
+/// Follows the directions from `node`, which is `steps` steps into them, until it reaches a node
+/// ending in Z (taking at least one step). Returns how many steps that took and which node it was.
+fn steps_to_z<'a>(
+    instructions: &Instructions<'a>,
+    mut node: &'a str,
+    steps: u64,
+) -> Result<(u64, &'a str)> {
+    let start = node;
+    let mut direction_idx = (steps % instructions.directions.len() as u64) as usize;
+
+    // there are only so many (node, direction) pairs, so a walk that goes on longer than that is
+    // going round a loop without a Z in it
+    let limit = instructions.map.len() * instructions.directions.len();
+
+    for taken in 1..=limit as u64 {
+        (node, direction_idx) = instructions
+            .step(node, direction_idx)
+            .ok_or_else(|| eyre!("node {node} is not defined"))?;
+
+        if node.ends_with('Z') {
+            return Ok((taken, node));
+        }
+    }
+
+    bail!("{start} never reaches a node ending in Z")
+}
+
+fn solve_part_2(instructions: &Instructions<'_>) -> Result<u64> {
+    // All nodes that start with A:
+    let nodes: Vec<&str> = instructions
+        .map
+        .keys()
+        .filter(|key| key.ends_with('A'))
+        .copied()
+        .collect();
+
+    if nodes.is_empty() {
+        bail!("no nodes end in A");
+    }
+
+    // Each ghost reaches its first Z after `first` steps and its next one `cycle` steps after that.
+    // The congruences below only hold if it then keeps coming back to that same Z every `cycle`
+    // steps. Once it's back on it at the same point in the directions as the first time, it repeats
+    // exactly, so that's how far it has to be followed to be sure. Anything else would need a
+    // cleverer search than this, so it's an error rather than a wrong answer:
+    let directions = instructions.directions.len() as u64;
+    let mut congruences = Vec::new();
+    let mut latest_first = 0;
+
+    for node in nodes {
+        let (first, z) = steps_to_z(instructions, node, 0)?;
+        let (cycle, next_z) = steps_to_z(instructions, z, first)?;
+
+        if next_z != z {
+            bail!(
+                "the ghost from {node} goes on from {z} to {next_z} instead of looping back to it"
+            );
+        }
+
+        let mut steps = first + cycle;
+        while steps % directions != first % directions {
+            let (next_cycle, next_z) = steps_to_z(instructions, z, steps)?;
+            if (next_cycle, next_z) != (cycle, z) {
+                bail!("the ghost from {node} doesn't come back round to {z} every {cycle} steps");
+            }
+
+            steps += cycle;
+        }
+
+        congruences.push((first as i128, cycle as i128));
+        latest_first = latest_first.max(first as i128);
+    }
+
+    // they "lineup" on a step that's `first` plus some number of cycles for every ghost, which is
+    // what the chinese remainder theorem solves for. (With the real inputs every ghost's first Z is
+    // exactly one cycle in, so this is just the least common multiple of the cycles.)
+    let (mut steps, period) = math::crt(congruences)
+        .ok_or_else(|| eyre!("the ghosts are never all on nodes ending in Z at once"))?;
+    if steps < latest_first {
+        steps += (latest_first - steps + period - 1) / period * period;
+    }
+
+    steps.try_into().map_err(|_| {
+        eyre!("the ghosts only line up after {steps} steps, which doesn't fit in a u64")
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 8;
+
+    type Parsed<'a> = Instructions<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        let instructions = parse::parse_all(Self::DAY, input, parse_input)?;
+
+        // every node has to lead somewhere, so point at the first one that leads to a node that
+        // isn't defined
+        let undefined = instructions
+            .map
+            .values()
+            .flat_map(|&(left, right)| [left, right])
+            .filter(|node| !instructions.map.contains_key(node))
+            .min_by_key(|node| node.as_ptr());
+
+        match undefined {
+            Some(node) => Err(ParseError::new(
+                Self::DAY,
+                input,
+                node,
+                format!("node {node} is not defined"),
+            )),
+            None => Ok(instructions),
+        }
+    }
+
+    fn part_1(instructions: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(solve_part_1(instructions)?.into())
+    }
+
+    fn part_2(instructions: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(solve_part_2(instructions)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/2023/day_8/example_1.txt");
+
+    #[test]
+    fn test_parse_input() {
+        let (_, instructions) = parse_input(INPUT.trim()).unwrap();
+        assert_eq!(instructions.directions, vec!['R', 'L']);
+        assert_eq!(instructions.map.get("AAA"), Some(&("BBB", "CCC")));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let err = Day8::parse("RXL\n\nAAA = (BBB, CCC)").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 2));
+
+        let err = Day8::parse("RL\n\nAAA = (BBB, CCC)\nBBB = (DDD EEE)").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 11));
+
+        let err = Day8::parse("RL\n\nAAA = (AAA, BBB)\nBBB = (CCC, AAA)").unwrap_err();
+        assert_eq!((err.line, err.column), (4, 8));
+        assert_eq!(err.message, "node CCC is not defined");
+    }
+
+    #[test]
+    fn test_part_1_errors() {
+        let instructions = Day8::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)").unwrap();
+        let err = solve_part_1(&instructions).unwrap_err();
+        assert_eq!(err.to_string(), "there's no AAA node to start from");
+
+        let instructions = Day8::parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let err = solve_part_1(&instructions).unwrap_err();
+        assert_eq!(err.to_string(), "ZZZ can't be reached from AAA");
+    }
+
+    #[test]
+    fn test_part_2_with_offset_cycles() {
+        // the first ghost is on a Z every odd step, the second on steps 2, 5, 8...
+        let input = "\
+L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22C, 22C)
+22C = (22D, 22D)
+22D = (22Z, 22Z)";
+        let instructions = Day8::parse(input).unwrap();
+
+        assert_eq!(solve_part_2(&instructions).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_part_2_errors() {
+        // 22A goes round a loop that never passes a Z
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22A, 22A)";
+        let err = solve_part_2(&Day8::parse(input).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "22A never reaches a node ending in Z");
+
+        // one ghost is only ever on a Z on odd steps, and the other only on even ones
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11Z, 11Z)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)";
+        let err = solve_part_2(&Day8::parse(input).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the ghosts are never all on nodes ending in Z at once"
+        );
+
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (12Z, 12Z)\n12Z = (12Z, 12Z)";
+        let err = solve_part_2(&Day8::parse(input).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the ghost from 11A goes on from 11Z to 12Z instead of looping back to it"
+        );
+
+        // 11Z comes round 3 steps after going right from it, but only 2 after going left
+        let input = "LR\n\n11A = (11Z, 11Z)\n11Z = (11D, 11B)\n11B = (11C, 11C)\n11C = (11Z, 11Z)\n11D = (11Z, 11Z)";
+        let err = solve_part_2(&Day8::parse(input).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the ghost from 11A doesn't come back round to 11Z every 3 steps"
+        );
+
+        let err = solve_part_2(&Day8::parse("L\n\nZZZ = (ZZZ, ZZZ)").unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "no nodes end in A");
+    }
+}
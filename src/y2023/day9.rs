@@ -1,27 +1,11 @@
-use nom::{
-    character::complete::{i64, space0},
-    multi::fold_many1,
-    sequence::tuple,
-    IResult,
-};
-
-fn parse_line_of_i64(input: &str) -> IResult<&str, Vec<i64>> {
-    fold_many1(
-        tuple((i64, space0)),
-        Vec::new,
-        |mut acc: Vec<i64>, (i, _)| {
-            acc.push(i);
-            acc
-        },
-    )(input)
-}
+use color_eyre::Result;
+
+use crate::error::ParseError;
+use crate::parse::{self, numbers};
+use crate::solution::{Answer, Solution};
 
-fn parse_input(input: &str) -> Vec<Vec<i64>> {
-    input
-        .trim()
-        .lines()
-        .map(|line| parse_line_of_i64(line.trim()).unwrap().1)
-        .collect::<Vec<_>>()
+fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse::parse_lines(Day9::DAY, input, numbers)
 }
 
 /// Given an array like "0 3 6 9 12 15" reduce it to "0 0 0 0" and return the intermediate sequences
@@ -55,8 +39,8 @@ fn get_between_seqs(seq: &[i64]) -> Vec<Vec<i64>> {
     between_seqs
 }
 
-pub fn solve_part_1(input: &str) -> i64 {
-    parse_input(input).iter().fold(0, |result, top_seq| {
+fn solve_part_1(sequences: &[Vec<i64>]) -> i64 {
+    sequences.iter().fold(0, |result, top_seq| {
         let between_seqs = get_between_seqs(top_seq);
 
         let below = between_seqs.iter().rev().fold(0, |below, seq| {
@@ -70,8 +54,8 @@ pub fn solve_part_1(input: &str) -> i64 {
     })
 }
 
-pub fn solve_part_2(input: &str) -> i64 {
-    parse_input(input).iter().fold(0, |result, top_seq| {
+fn solve_part_2(sequences: &[Vec<i64>]) -> i64 {
+    sequences.iter().fold(0, |result, top_seq| {
         let between_seqs = get_between_seqs(top_seq);
 
         let below = between_seqs.iter().rev().fold(0, |below, seq| {
@@ -86,6 +70,27 @@ pub fn solve_part_2(input: &str) -> i64 {
     })
 }
 
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u32 = 2023;
+    const DAY: u32 = 9;
+
+    type Parsed<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(sequences: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(solve_part_1(sequences).into())
+    }
+
+    fn part_2(sequences: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(solve_part_2(sequences).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,28 +98,12 @@ mod tests {
     #[test]
     fn test_parse_line() {
         let input = "1 -2 4";
-        assert_eq!(parse_line_of_i64(input), Ok(("", vec![1, -2, 4])));
-    }
-
-    #[test]
-    fn test_example_input() {
-        let input = r#"
-0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45
-        "#;
-
-        assert_eq!(solve_part_1(input.trim()), 114);
+        assert_eq!(numbers(input), Ok(("", vec![1i64, -2, 4])));
     }
 
     #[test]
-    fn test_example_part_2() {
-        let input = r#"
-0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45
-        "#;
-
-        assert_eq!(solve_part_2(input.trim()), 2);
+    fn test_parse_error_location() {
+        let err = Day9::parse("0 3 6\n1 3 six 10").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
     }
 }
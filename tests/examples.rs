@@ -0,0 +1,54 @@
+//! Turns every fixture under `examples/` into a test per part, e.g.
+//! `y2023::day_5::example::part_1`.
+//! Answers marked as pending become ignored tests, which `cargo test -- --ignored` still runs.
+
+use std::path::Path;
+
+use aoc::fixtures::{self, Expected, Fixture, DEFAULT_EXAMPLES_DIR};
+use aoc::runner;
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn check(fixture: &Fixture, expected: &Expected) -> Result<(), Failed> {
+    let day = runner::get(fixture.year, fixture.day).ok_or_else(|| {
+        format!(
+            "{} day {} has examples but is not registered",
+            fixture.year, fixture.day
+        )
+    })?;
+
+    let mut answers = day
+        .solve(&fixture.input, &[expected.part])
+        .map_err(|e| format!("{e}\n{}", e.excerpt()))?;
+    let actual = answers.remove(0)?.to_string();
+
+    if actual != expected.answer {
+        return Err(format!("expected {}, got {}", expected.answer, actual).into());
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args = Arguments::from_args();
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_EXAMPLES_DIR);
+    let fixtures = fixtures::discover(&root).expect("could not load the example fixtures");
+
+    let trials = fixtures
+        .into_iter()
+        .flat_map(|fixture| {
+            fixture.expected.clone().into_iter().map(move |expected| {
+                let name = format!(
+                    "y{}::day_{}::{}::part_{}",
+                    fixture.year, fixture.day, fixture.name, expected.part
+                );
+                let pending = expected.pending;
+                let fixture = fixture.clone();
+
+                Trial::test(name, move || check(&fixture, &expected)).with_ignored_flag(pending)
+            })
+        })
+        .collect();
+
+    libtest_mimic::run(&args, trials).exit();
+}